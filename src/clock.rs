@@ -0,0 +1,95 @@
+//! Injectable time source for timing measurements.
+
+use core::time::Duration;
+
+#[cfg(any(test, feature = "testing"))]
+use portable_atomic_util::Arc;
+#[cfg(any(test, feature = "testing"))]
+use spin::Mutex;
+
+/// Source of the current time.
+///
+/// The runtime only ever compares two readings, so [`now`](Self::now) may be
+/// measured from any fixed starting point. Implement this for your platform's
+/// timer on embedded targets, use `SystemClock` on std, or
+/// [`TestClock`](crate::TestClock) for deterministic tests.
+pub trait Clock {
+    /// Time elapsed since this clock's starting point.
+    fn now(&self) -> Duration;
+}
+
+/// [`Clock`] backed by [`std::time::Instant`].
+///
+/// Only available without the `no_std` feature.
+#[cfg(not(feature = "no_std"))]
+pub struct SystemClock {
+    start: std::time::Instant,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl SystemClock {
+    /// Create a clock measuring from the current instant.
+    pub fn new() -> Self {
+        Self {
+            start: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+/// Manually advanced clock for deterministic tests.
+///
+/// Only available with the `testing` feature or during tests.
+///
+/// Clones share the same time, so a test can keep one clone to
+/// [`advance`](Self::advance) while the runtime reads another.
+///
+/// # Example
+///
+/// ```rust
+/// use core::time::Duration;
+/// use oxide_mvu::{Clock, TestClock};
+///
+/// let clock = TestClock::new();
+/// clock.advance(Duration::from_millis(16));
+///
+/// assert_eq!(clock.now(), Duration::from_millis(16));
+/// ```
+#[derive(Clone, Default)]
+pub struct TestClock {
+    now: Arc<Mutex<Duration>>,
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl TestClock {
+    /// Create a clock starting at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock() += duration;
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Clock for TestClock {
+    fn now(&self) -> Duration {
+        *self.now.lock()
+    }
+}
@@ -3,12 +3,18 @@
 #[cfg(feature = "no_std")]
 use alloc::boxed::Box;
 #[cfg(feature = "no_std")]
+use alloc::vec;
+#[cfg(feature = "no_std")]
 use alloc::vec::Vec;
 
 use core::future::Future;
 use core::pin::Pin;
+use core::task::Poll;
+
+use portable_atomic::{AtomicUsize, Ordering};
+use portable_atomic_util::Arc;
 
-use crate::Emitter;
+use crate::{Bridge, Emitter, ModelAccess, Persistence};
 
 /// Declarative description of events to be processed.
 ///
@@ -39,24 +45,70 @@ use crate::Emitter;
 /// // No side effects
 /// let effect: Effect<Event> = Effect::none();
 /// ```
-pub struct Effect<Event: Send>(Box<dyn FnOnceBox<Event> + Send>);
+pub struct Effect<Event: Send>(Kind<Event>);
+
+/// Internal representation of an effect.
+///
+/// Simple effects are kept as data so they can be inspected (e.g. by
+/// `EffectMatcher`); everything else is an opaque deferred function.
+enum Kind<Event: Send> {
+    None,
+    Just(Event),
+    Batch(Vec<Effect<Event>>),
+    Labeled(&'static str, Box<Effect<Event>>),
+    Deferred(Box<dyn FnOnceBox<Event> + Send>),
+}
+
+/// Conversion applied by [`Effect::map`].
+type MapFn<Event, Parent> = dyn Fn(Event) -> Parent + Send + Sync;
 
 impl<Event: Send + 'static> Effect<Event> {
     /// Execute the effect, consuming it and returning a future.
     ///
     /// The returned future will be spawned on your async runtime using the provided spawner.
     pub fn execute(self, emitter: &Emitter<Event>) -> Pin<Box<dyn Future<Output = ()> + Send>> {
-        self.0.call_box(emitter)
+        match self.0 {
+            Kind::None => Box::pin(async {}),
+            Kind::Just(event) => {
+                let emitter = emitter.clone();
+                Box::pin(async move { emitter.emit(event) })
+            }
+            Kind::Batch(effects) => {
+                let emitter = emitter.clone();
+                Box::pin(async move {
+                    for effect in effects {
+                        effect.execute(&emitter).await;
+                    }
+                })
+            }
+            Kind::Labeled(_, effect) => effect.execute(emitter),
+            Kind::Deferred(f) => f.call_box(emitter),
+        }
+    }
+
+    /// The label attached with [`labeled`](Self::labeled), if any.
+    pub fn label(&self) -> Option<&'static str> {
+        match &self.0 {
+            Kind::Labeled(label, _) => Some(label),
+            _ => None,
+        }
     }
 
     /// Create an empty effect.
     ///
     /// This is private - use [`Effect::none()`] instead.
     fn new() -> Self {
-        fn empty_fn<Event: Send>(_: &Emitter<Event>) -> Pin<Box<dyn Future<Output = ()> + Send>> {
-            Box::pin(async {})
-        }
-        Self(Box::new(empty_fn))
+        Self(Kind::None)
+    }
+
+    /// Create an effect from a function producing the future to execute.
+    fn deferred<F>(f: F) -> Self
+    where
+        F: for<'a> FnOnce(&'a Emitter<Event>) -> Pin<Box<dyn Future<Output = ()> + Send>>
+            + Send
+            + 'static,
+    {
+        Self(Kind::Deferred(Box::new(f)))
     }
 
     /// Create an effect that just emits a single event.
@@ -77,10 +129,106 @@ impl<Event: Send + 'static> Effect<Event> {
     where
         Event: Send + 'static,
     {
-        Self(Box::new(move |emitter: &Emitter<Event>| {
+        Self(Kind::Just(event))
+    }
+
+    /// Create an effect that emits an event into another runtime.
+    ///
+    /// The event is sent through `bridge` into the target runtime's queue rather
+    /// than this runtime's. If the target runtime has been dropped by the time the
+    /// effect executes, the event is discarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::{Bridge, Effect, RuntimeHandle};
+    ///
+    /// enum ChildEvent { Refresh }
+    /// enum ParentEvent { Navigated }
+    ///
+    /// fn refresh_child<ChildModel>(child: &RuntimeHandle<ChildEvent, ChildModel>) -> Effect<ParentEvent> {
+    ///     Effect::forward_to(&Bridge::new(child), ChildEvent::Refresh)
+    /// }
+    /// ```
+    pub fn forward_to<Target>(bridge: &Bridge<Target>, event: Target) -> Self
+    where
+        Target: Send + 'static,
+    {
+        let bridge = bridge.clone();
+        Self::deferred(move |_: &Emitter<Event>| {
+            Box::pin(async move { bridge.emit(event) }) as Pin<Box<dyn Future<Output = ()> + Send>>
+        })
+    }
+
+    /// Create an effect from a closure that runs at most once.
+    ///
+    /// The closure is called with the runtime's emitter when the effect executes.
+    /// Because it is an `FnOnce`, it can consume move-only resources, such as
+    /// handing a value to a oneshot channel. Executing an effect consumes it, so
+    /// the closure can never run more than once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::Effect;
+    ///
+    /// enum Event { Greeted(String) }
+    ///
+    /// let name = String::from("world");
+    /// let effect = Effect::once_fn(move |emitter| {
+    ///     emitter.emit(Event::Greeted(name));
+    /// });
+    /// ```
+    pub fn once_fn<F>(f: F) -> Self
+    where
+        F: FnOnce(&Emitter<Event>) + Send + 'static,
+    {
+        Self::deferred(move |emitter: &Emitter<Event>| {
             let emitter = emitter.clone();
-            Box::pin(async move { emitter.emit(event) }) as Pin<Box<dyn Future<Output = ()> + Send>>
-        }))
+            Box::pin(async move { f(&emitter) }) as Pin<Box<dyn Future<Output = ()> + Send>>
+        })
+    }
+
+    /// Create an effect that emits the event if there is one.
+    ///
+    /// `Some(event)` behaves like [`Effect::just`], while `None` behaves like
+    /// [`Effect::none`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::Effect;
+    ///
+    /// enum Event { LimitReached }
+    ///
+    /// let count = 10;
+    /// let effect = Effect::from_option((count >= 10).then_some(Event::LimitReached));
+    /// ```
+    pub fn from_option(event: Option<Event>) -> Self {
+        match event {
+            Some(event) => Self::just(event),
+            None => Self::none(),
+        }
+    }
+
+    /// Create an effect that emits whichever event the result holds.
+    ///
+    /// Both the `Ok` and `Err` events are emitted as with [`Effect::just`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::Effect;
+    ///
+    /// enum Event { Parsed(i32), ParseFailed }
+    ///
+    /// let result = "42".parse().map(Event::Parsed).map_err(|_| Event::ParseFailed);
+    /// let effect = Effect::from_result_event(result);
+    /// ```
+    pub fn from_result_event(result: Result<Event, Event>) -> Self {
+        match result {
+            Ok(event) | Err(event) => Self::just(event),
+        }
     }
 
     /// Create an empty effect.
@@ -120,14 +268,434 @@ impl<Event: Send + 'static> Effect<Event> {
     /// ]);
     /// ```
     pub fn batch(effects: Vec<Effect<Event>>) -> Self {
-        Self(Box::new(move |emitter: &Emitter<Event>| {
+        Self(Kind::Batch(effects))
+    }
+
+    /// Convert the events of this effect with `f`.
+    ///
+    /// Used to lift a child component's effect into its parent's event type.
+    /// The structure of the effect is kept: batches are mapped effect by
+    /// effect, labels are kept, and effects built with [`none`](Self::none) and
+    /// [`just`](Self::just) remain inspectable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::Effect;
+    ///
+    /// enum ChildEvent { Loaded }
+    /// enum ParentEvent { Child(ChildEvent) }
+    ///
+    /// let effect: Effect<ParentEvent> = Effect::just(ChildEvent::Loaded).map(ParentEvent::Child);
+    /// ```
+    pub fn map<Parent, F>(self, f: F) -> Effect<Parent>
+    where
+        Parent: Send + 'static,
+        F: Fn(Event) -> Parent + Send + Sync + 'static,
+    {
+        let f: Box<MapFn<Event, Parent>> = Box::new(f);
+        self.map_shared(&Arc::from(f))
+    }
+
+    /// [`map`](Self::map) with a conversion shared by the effects of a batch.
+    fn map_shared<Parent>(self, f: &Arc<MapFn<Event, Parent>>) -> Effect<Parent>
+    where
+        Parent: Send + 'static,
+    {
+        match self.0 {
+            Kind::None => Effect::none(),
+            Kind::Just(event) => Effect::just(f(event)),
+            Kind::Batch(effects) => Effect::batch(
+                effects
+                    .into_iter()
+                    .map(|effect| effect.map_shared(f))
+                    .collect(),
+            ),
+            Kind::Labeled(label, effect) => effect.map_shared(f).labeled(label),
+            kind => {
+                let f = f.clone();
+                Effect::deferred(move |emitter: &Emitter<Parent>| {
+                    let emitter = emitter.clone();
+                    Self(kind).execute(&Emitter::from_sink(move |event| emitter.emit(f(event))))
+                })
+            }
+        }
+    }
+
+    /// Merge the effects of two children with different event types.
+    ///
+    /// Each effect is converted with its mapping function as by
+    /// [`map`](Self::map), and the results are batched, `a` first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::Effect;
+    ///
+    /// enum SearchEvent { Searched }
+    /// enum CartEvent { Updated }
+    /// enum Event { Search(SearchEvent), Cart(CartEvent) }
+    ///
+    /// let effect = Effect::merge2(
+    ///     Effect::just(SearchEvent::Searched),
+    ///     Event::Search,
+    ///     Effect::just(CartEvent::Updated),
+    ///     Event::Cart,
+    /// );
+    /// ```
+    pub fn merge2<A, MapA, B, MapB>(a: Effect<A>, map_a: MapA, b: Effect<B>, map_b: MapB) -> Self
+    where
+        A: Send + 'static,
+        B: Send + 'static,
+        MapA: Fn(A) -> Event + Send + Sync + 'static,
+        MapB: Fn(B) -> Event + Send + Sync + 'static,
+    {
+        a.map(map_a) + b.map(map_b)
+    }
+
+    /// Attach a label identifying this effect in diagnostics.
+    ///
+    /// Labels don't change what the effect does. They are reported by
+    /// [`MvuRuntime::with_effect_fanout`](crate::MvuRuntime::with_effect_fanout),
+    /// and [`EffectMatcher`] matches a labeled effect as if it were unlabeled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::Effect;
+    ///
+    /// enum Event { Loaded }
+    ///
+    /// let effect = Effect::just(Event::Loaded).labeled("load_profile");
+    /// assert_eq!(effect.label(), Some("load_profile"));
+    /// ```
+    pub fn labeled(self, label: &'static str) -> Self {
+        match self.0 {
+            Kind::Labeled(_, effect) => Self(Kind::Labeled(label, effect)),
+            kind => Self(Kind::Labeled(label, Box::new(Self(kind)))),
+        }
+    }
+
+    /// Emit a follow-up event once this effect's future has completed.
+    ///
+    /// The event is emitted from within the spawned task, strictly after the
+    /// wrapped future resolves and therefore after any events it emitted. This is
+    /// useful for flows such as "loading finished, now hide the spinner".
+    ///
+    /// If the wrapped future panics, the follow-up event is **not** emitted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::Effect;
+    ///
+    /// enum Event { DataLoaded(String), LoadingFinished }
+    ///
+    /// let effect = Effect::from_async(|emitter| async move {
+    ///     emitter.emit(Event::DataLoaded("data".to_string()));
+    /// })
+    /// .on_complete(Event::LoadingFinished);
+    /// ```
+    pub fn on_complete(self, event: Event) -> Self {
+        Self::deferred(move |emitter: &Emitter<Event>| {
+            let future = self.execute(emitter);
             let emitter = emitter.clone();
             Box::pin(async move {
-                for effect in effects {
-                    effect.execute(&emitter).await;
-                }
+                future.await;
+                emitter.emit(event);
+            }) as Pin<Box<dyn Future<Output = ()> + Send>>
+        })
+    }
+
+    /// Emit an event when this effect's task ends, however it ends.
+    ///
+    /// Unlike [`on_complete`](Self::on_complete), the event is also emitted if the
+    /// spawned future is dropped before finishing (e.g. cancelled by the executor)
+    /// or unwinds from a panic, much like a `finally` block. This makes it suitable
+    /// for clearing spinners or releasing locks regardless of the outcome.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::Effect;
+    ///
+    /// enum Event { Saved, SaveFailed, SaveSettled }
+    ///
+    /// # async fn save() -> Result<(), ()> { Ok(()) }
+    /// let effect = Effect::from_async(|emitter| async move {
+    ///     match save().await {
+    ///         Ok(()) => emitter.emit(Event::Saved),
+    ///         Err(()) => emitter.emit(Event::SaveFailed),
+    ///     }
+    /// })
+    /// .finally(Event::SaveSettled);
+    /// ```
+    pub fn finally(self, event: Event) -> Self {
+        Self::deferred(move |emitter: &Emitter<Event>| {
+            let future = self.execute(emitter);
+            let guard = EmitOnDrop {
+                emitter: emitter.clone(),
+                event: Some(event),
+            };
+            Box::pin(async move {
+                let _guard = guard;
+                future.await;
             }) as Pin<Box<dyn Future<Output = ()> + Send>>
-        }))
+        })
+    }
+
+    /// Deliver an effect's events only if the model it was returned with is still current.
+    ///
+    /// Return it from `update` for optimistic work. The runtime commits the
+    /// updated model before executing the effect, so the [`ModelAccess::version`]
+    /// seen at execution is the version of that model. The version is checked
+    /// once, when the inner effect emits its first event: if no other model has
+    /// been committed in between, that event and every later one are delivered,
+    /// even though processing them commits new models. Otherwise the result was
+    /// based on a model that has since been replaced, and all of the effect's
+    /// events are discarded instead of clobbering newer state.
+    ///
+    /// The version changes on every commit, so any event processed before the
+    /// first emit, including unrelated ones such as ticks, makes the effect stale.
+    ///
+    /// `access` must already be observing the model when the effect executes,
+    /// see [`ModelAccess::new`]. An access that hasn't observed a model yet has
+    /// nothing to compare against, so the effect's events are delivered
+    /// unchecked. This is always the case for effects returned from
+    /// [`MvuLogic::init`], which execute before the event loop picks up new
+    /// subscriptions.
+    ///
+    /// [`MvuLogic::init`]: crate::MvuLogic::init
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::{Effect, ModelAccess};
+    ///
+    /// #[derive(Clone)]
+    /// struct Model { draft: String }
+    ///
+    /// enum Event { DraftValidated }
+    ///
+    /// fn validate(access: &ModelAccess<Model>) -> Effect<Event> {
+    ///     Effect::if_version(access, Effect::from_async(|emitter| async move {
+    ///         // Validate the draft remotely
+    ///         emitter.emit(Event::DraftValidated);
+    ///     }))
+    /// }
+    /// ```
+    pub fn if_version<Model>(access: &ModelAccess<Model>, effect: Effect<Event>) -> Self
+    where
+        Model: Clone + Send + 'static,
+    {
+        let access = access.clone();
+        Self::deferred(move |emitter: &Emitter<Event>| {
+            let version = access.version();
+            let current = spin::Once::<bool>::new();
+            let emitter = emitter.clone();
+            effect.execute(&Emitter::from_sink(move |event| {
+                let current = *current.call_once(|| version == 0 || access.version() == version);
+                if current {
+                    emitter.emit(event);
+                }
+            }))
+        })
+    }
+
+    /// Tie an effect to an [`EffectScope`] so it can be cancelled as a group.
+    ///
+    /// Once [`EffectScope::cancel_all`] is called, the effect stops emitting
+    /// events. Its future finishes the next time it is polled, and if it hasn't
+    /// started yet it won't run at all. Effects scoped after the call are not
+    /// affected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::{Effect, EffectScope};
+    ///
+    /// enum Event { Tick }
+    ///
+    /// let screen = EffectScope::new();
+    /// let effect = Effect::scoped(&screen, Effect::from_async(|emitter| async move {
+    ///     // Poll something for as long as the screen is shown
+    ///     emitter.emit(Event::Tick);
+    /// }));
+    ///
+    /// // Navigating away from the screen
+    /// screen.cancel_all();
+    /// ```
+    pub fn scoped(scope: &EffectScope, effect: Effect<Event>) -> Self {
+        let scope = scope.clone();
+        let generation = scope.generation.load(Ordering::Acquire);
+
+        Self::deferred(move |emitter: &Emitter<Event>| {
+            if scope.is_cancelled(generation) {
+                return Box::pin(async {}) as Pin<Box<dyn Future<Output = ()> + Send>>;
+            }
+
+            let target = emitter.clone();
+            let emit_scope = scope.clone();
+            let scoped_emitter = Emitter::from_sink(move |event| {
+                if !emit_scope.is_cancelled(generation) {
+                    target.emit(event);
+                }
+            });
+
+            let mut future = effect.execute(&scoped_emitter);
+            Box::pin(core::future::poll_fn(move |cx| {
+                if scope.is_cancelled(generation) {
+                    Poll::Ready(())
+                } else {
+                    future.as_mut().poll(cx)
+                }
+            }))
+        })
+    }
+
+    /// Run an effect only if a condition holds when it is executed.
+    ///
+    /// `condition` is checked at execution time rather than when the effect is
+    /// created, so it can observe external state that changed in between, such as
+    /// a flag cleared to cancel a no-longer-relevant follow-up. If it returns
+    /// `false`, the inner effect is discarded without running.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::Effect;
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// enum Event { ShowTooltip }
+    ///
+    /// let still_hovering = Arc::new(AtomicBool::new(true));
+    /// let hovering = still_hovering.clone();
+    ///
+    /// let effect = Effect::guard(
+    ///     move || hovering.load(Ordering::SeqCst),
+    ///     Effect::just(Event::ShowTooltip),
+    /// );
+    /// ```
+    pub fn guard<F>(condition: F, effect: Effect<Event>) -> Self
+    where
+        F: FnOnce() -> bool + Send + 'static,
+    {
+        Self::deferred(move |emitter: &Emitter<Event>| {
+            if condition() {
+                effect.execute(emitter)
+            } else {
+                Box::pin(async {}) as Pin<Box<dyn Future<Output = ()> + Send>>
+            }
+        })
+    }
+
+    /// Choose an effect based on the model at the time it is executed.
+    ///
+    /// When the effect runs, `selector` is called with the model currently held
+    /// by `access` and the effect it returns is executed. The model may have
+    /// changed since this effect was created, which allows effects such as "if
+    /// the model still says X, do Y". If `access` hasn't observed a model yet,
+    /// nothing is executed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::{Effect, ModelAccess};
+    ///
+    /// #[derive(Clone)]
+    /// struct Model { dirty: bool }
+    ///
+    /// enum Event { Save }
+    ///
+    /// fn save_if_dirty(access: &ModelAccess<Model>) -> Effect<Event> {
+    ///     Effect::select(access, |model: &Model| {
+    ///         Effect::from_option(model.dirty.then_some(Event::Save))
+    ///     })
+    /// }
+    /// ```
+    pub fn select<Model, F>(access: &ModelAccess<Model>, selector: F) -> Self
+    where
+        Model: Clone + Send + 'static,
+        F: FnOnce(&Model) -> Effect<Event> + Send + 'static,
+    {
+        let access = access.clone();
+        Self::deferred(move |emitter: &Emitter<Event>| {
+            access
+                .read(selector)
+                .unwrap_or_else(Effect::none)
+                .execute(emitter)
+        })
+    }
+
+    /// Emit an event derived from the model at the time the effect is executed.
+    ///
+    /// When the effect runs, `derive` is called with the model currently held by
+    /// `access`, and the event it returns, if any, is emitted. Unlike
+    /// [`select`](Self::select) this produces an event directly, which suits
+    /// syncing the current state to an external system. If `access` hasn't
+    /// observed a model yet, nothing is emitted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::{Effect, ModelAccess};
+    ///
+    /// #[derive(Clone)]
+    /// struct Model { unread: u32 }
+    ///
+    /// enum Event { ShowBadge(u32) }
+    ///
+    /// fn sync_badge(access: &ModelAccess<Model>) -> Effect<Event> {
+    ///     Effect::from_model(access, |model: &Model| {
+    ///         (model.unread > 0).then_some(Event::ShowBadge(model.unread))
+    ///     })
+    /// }
+    /// ```
+    pub fn from_model<Model, F>(access: &ModelAccess<Model>, derive: F) -> Self
+    where
+        Model: Clone + Send + 'static,
+        F: FnOnce(&Model) -> Option<Event> + Send + 'static,
+    {
+        Self::select(access, move |model| Effect::from_option(derive(model)))
+    }
+
+    /// Save the model at the time the effect is executed.
+    ///
+    /// When the effect runs, the model currently held by `access` is passed to
+    /// [`Persistence::save`]. Return this from `update` for explicit save points,
+    /// such as saving on quit, rather than saving after every update. If `access`
+    /// hasn't observed a model yet, nothing is saved. No event is emitted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::{Effect, ModelAccess, Persistence};
+    ///
+    /// #[derive(Clone)]
+    /// struct Model { draft: String }
+    ///
+    /// enum Event { Quit }
+    ///
+    /// struct DraftStore;
+    /// impl Persistence<Model> for DraftStore {
+    ///     fn save(&self, model: &Model) { /* write model.draft to disk */ }
+    /// }
+    ///
+    /// fn save_on_quit(access: &ModelAccess<Model>) -> Effect<Event> {
+    ///     Effect::persist(access, DraftStore)
+    /// }
+    /// ```
+    pub fn persist<Model, P>(access: &ModelAccess<Model>, persistence: P) -> Self
+    where
+        Model: Clone + Send + 'static,
+        P: Persistence<Model> + Send + 'static,
+    {
+        let access = access.clone();
+        Self::deferred(move |_emitter: &Emitter<Event>| {
+            access.read(|model| persistence.save(model));
+            Box::pin(async {}) as Pin<Box<dyn Future<Output = ()> + Send>>
+        })
     }
 
     /// Create an effect from an async function using a runtime-agnostic spawner.
@@ -192,10 +760,266 @@ impl<Event: Send + 'static> Effect<Event> {
         F: FnOnce(Emitter<Event>) -> Fut + Send + 'static,
         Fut: Future<Output = ()> + Send + 'static,
     {
-        Self(Box::new(move |emitter: &Emitter<Event>| {
+        Self::deferred(move |emitter: &Emitter<Event>| {
             let future = f(emitter.clone());
             Box::pin(future) as Pin<Box<dyn Future<Output = ()> + Send>>
-        }))
+        })
+    }
+
+    /// Create an effect that runs blocking work on its own thread.
+    ///
+    /// Only available without the `no_std` feature.
+    ///
+    /// Use this for CPU-bound or otherwise blocking work, such as parsing a large
+    /// file, that would stall the event loop or an async executor. When executed,
+    /// `f` is offloaded to a new thread via `std::thread::spawn` and the event it
+    /// returns is emitted. The effect's future completes once the event has been
+    /// emitted. For I/O-bound work prefer [`from_async`](Self::from_async).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::Effect;
+    ///
+    /// enum Event { Parsed(usize) }
+    ///
+    /// let document = String::from("a large document");
+    /// let effect = Effect::from_blocking(move || Event::Parsed(document.split(' ').count()));
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn from_blocking<F>(f: F) -> Self
+    where
+        F: FnOnce() -> Event + Send + 'static,
+    {
+        Self::deferred(move |emitter: &Emitter<Event>| {
+            let emitter = emitter.clone();
+            let (sender, receiver) = flume::bounded(1);
+            std::thread::spawn(move || {
+                sender.send(f()).ok();
+            });
+            Box::pin(async move {
+                // The worker only fails to send if `f` panicked
+                if let Ok(event) = receiver.recv_async().await {
+                    emitter.emit(event);
+                }
+            }) as Pin<Box<dyn Future<Output = ()> + Send>>
+        })
+    }
+}
+
+/// Combine two effects with `+`, equivalent to [`Effect::batch`].
+///
+/// `a + b` runs `a` then `b`. Empty effects are dropped and chained additions
+/// extend a single batch, so accumulating effects in a loop stays flat.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::Effect;
+///
+/// enum Event { Saved, Notify(u32) }
+///
+/// let mut effect = Effect::just(Event::Saved);
+/// for id in 0..3 {
+///     effect += Effect::just(Event::Notify(id));
+/// }
+/// ```
+impl<Event: Send + 'static> core::ops::Add for Effect<Event> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        match (self.0, rhs.0) {
+            (Kind::None, rhs) => Self(rhs),
+            (lhs, Kind::None) => Self(lhs),
+            (Kind::Batch(mut effects), rhs) => {
+                effects.push(Self(rhs));
+                Self(Kind::Batch(effects))
+            }
+            (lhs, rhs) => Self::batch(vec![Self(lhs), Self(rhs)]),
+        }
+    }
+}
+
+impl<Event: Send + 'static> core::ops::AddAssign for Effect<Event> {
+    fn add_assign(&mut self, rhs: Self) {
+        let lhs = core::mem::replace(self, Effect::none());
+        *self = lhs + rhs;
+    }
+}
+
+/// Group of effects that can be cancelled together.
+///
+/// Effects are added to a scope with [`Effect::scoped`]. Calling
+/// [`cancel_all`](Self::cancel_all) cancels every effect scoped so far, which is
+/// useful for abandoning the in-flight work of a screen the user navigated away
+/// from. The scope remains usable afterwards for new effects.
+///
+/// Scopes are cheap to clone; clones share the same set of effects.
+#[derive(Clone, Default)]
+pub struct EffectScope {
+    generation: Arc<AtomicUsize>,
+}
+
+impl EffectScope {
+    /// Create an empty scope.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel every effect scoped so far.
+    pub fn cancel_all(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+    }
+
+    /// Whether effects scoped during `generation` have been cancelled.
+    fn is_cancelled(&self, generation: usize) -> bool {
+        self.generation.load(Ordering::Acquire) != generation
+    }
+}
+
+/// Emits its event when dropped, used by [`Effect::finally`].
+struct EmitOnDrop<Event: Send + 'static> {
+    emitter: Emitter<Event>,
+    event: Option<Event>,
+}
+
+impl<Event: Send + 'static> Drop for EmitOnDrop<Event> {
+    fn drop(&mut self) {
+        if let Some(event) = self.event.take() {
+            self.emitter.emit(event);
+        }
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+/// Expected shape of an [`Effect`], for asserting effects without executing them.
+///
+/// Only available with the `testing` feature or during tests.
+///
+/// Effects built with [`Effect::none`], [`Effect::just`] and [`Effect::batch`] can
+/// be matched structurally. Any other effect (e.g. [`Effect::from_async`]) is
+/// opaque and never matches.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{Effect, EffectMatcher};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Event { A, B }
+///
+/// let effect = Effect::batch(vec![Effect::just(Event::A), Effect::just(Event::B)]);
+///
+/// assert!(EffectMatcher::Batch(vec![
+///     EffectMatcher::Just(Event::A),
+///     EffectMatcher::Just(Event::B),
+/// ])
+/// .matches(&effect));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum EffectMatcher<Event> {
+    /// Matches [`Effect::none`].
+    None,
+    /// Matches [`Effect::just`] with an equal event.
+    Just(Event),
+    /// Matches [`Effect::batch`] whose effects match each matcher in order.
+    Batch(Vec<EffectMatcher<Event>>),
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl<Event: PartialEq + Send> EffectMatcher<Event> {
+    /// Whether `effect` has the shape described by this matcher.
+    pub fn matches(&self, effect: &Effect<Event>) -> bool {
+        match (self, &effect.0) {
+            (EffectMatcher::None, Kind::None) => true,
+            (EffectMatcher::Just(expected), Kind::Just(actual)) => expected == actual,
+            (EffectMatcher::Batch(matchers), Kind::Batch(effects)) => {
+                matchers.len() == effects.len()
+                    && matchers
+                        .iter()
+                        .zip(effects)
+                        .all(|(matcher, effect)| matcher.matches(effect))
+            }
+            (matcher, Kind::Labeled(_, effect)) => matcher.matches(effect),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+/// Structure of an [`Effect`] as plain data, as returned by
+/// [`dry_update`](crate::dry_update).
+///
+/// Only available with the `testing` feature or during tests.
+///
+/// Mirrors how the effect was built. Effects whose behavior is only known by
+/// running them, such as [`Effect::from_async`], are described as `Async`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EffectDescription<Event> {
+    /// Built with [`Effect::none`].
+    None,
+    /// Built with [`Effect::just`].
+    Just(Event),
+    /// Built with [`Effect::batch`].
+    Batch(Vec<EffectDescription<Event>>),
+    /// Built with [`Effect::labeled`].
+    Labeled(&'static str, Box<EffectDescription<Event>>),
+    /// Any other effect, which can't be described without executing it.
+    Async,
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl<Event: Send> Effect<Event> {
+    /// Describe this effect without executing it.
+    pub(crate) fn describe(self) -> EffectDescription<Event> {
+        match self.0 {
+            Kind::None => EffectDescription::None,
+            Kind::Just(event) => EffectDescription::Just(event),
+            Kind::Batch(effects) => {
+                EffectDescription::Batch(effects.into_iter().map(Effect::describe).collect())
+            }
+            Kind::Labeled(label, effect) => {
+                EffectDescription::Labeled(label, Box::new(effect.describe()))
+            }
+            Kind::Deferred(_) => EffectDescription::Async,
+        }
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+/// Labels of executed effects, in the order they started executing.
+pub(crate) type ExecutionLog = Arc<spin::Mutex<Vec<Option<&'static str>>>>;
+
+#[cfg(any(test, feature = "testing"))]
+impl<Event: Send + 'static> Effect<Event> {
+    /// Record the label of each effect of this batch in `log` as it starts
+    /// executing.
+    ///
+    /// A batch is recorded per effect it contains and empty effects aren't
+    /// recorded. Labels are kept, so the recorded effects behave as before.
+    pub(crate) fn record_execution(self, log: &ExecutionLog) -> Self {
+        match self.0 {
+            Kind::None => Self(Kind::None),
+            Kind::Batch(effects) => Self::batch(
+                effects
+                    .into_iter()
+                    .map(|effect| effect.record_execution(log))
+                    .collect(),
+            ),
+            kind => {
+                let effect = Self(kind);
+                let label = effect.label();
+                let log = log.clone();
+                let recorded = Self::deferred(move |emitter: &Emitter<Event>| {
+                    log.lock().push(label);
+                    effect.execute(emitter)
+                });
+                match label {
+                    Some(label) => recorded.labeled(label),
+                    None => recorded,
+                }
+            }
+        }
     }
 }
 
@@ -1,6 +1,17 @@
 //! Event emitter for embedding callbacks in Props.
 
+#[cfg(feature = "no_std")]
+use alloc::boxed::Box;
+
+#[cfg(feature = "futures")]
+use core::convert::Infallible;
+#[cfg(feature = "futures")]
+use core::pin::Pin;
+#[cfg(feature = "futures")]
+use core::task::{Context, Poll};
+
 use flume::Sender;
+use portable_atomic_util::Arc;
 
 /// Event emitter that can be embedded in Props.
 ///
@@ -55,18 +66,38 @@ use flume::Sender;
 ///     }
 /// }
 /// ```
-pub struct Emitter<Event: Send>(pub(crate) Sender<Event>);
+pub struct Emitter<Event: Send>(Target<Event>);
+
+/// Destination of emitted events.
+enum Target<Event: Send> {
+    /// Sends directly into a runtime's event channel.
+    Channel(Sender<Event>),
+    /// Forwards through a function, typically wrapping another emitter.
+    Sink(Arc<dyn Fn(Event) + Send + Sync>),
+}
 
 impl<Event: Send> Clone for Emitter<Event> {
     fn clone(&self) -> Self {
-        Self(self.0.clone())
+        match &self.0 {
+            Target::Channel(sender) => Self(Target::Channel(sender.clone())),
+            Target::Sink(sink) => Self(Target::Sink(sink.clone())),
+        }
     }
 }
 
 impl<Event: Send> Emitter<Event> {
     /// Create a new emitter from a channel sender.
     pub(crate) fn new(sender: Sender<Event>) -> Self {
-        Self(sender)
+        Self(Target::Channel(sender))
+    }
+
+    /// Create an emitter that forwards every event through `sink`.
+    pub(crate) fn from_sink<F>(sink: F) -> Self
+    where
+        F: Fn(Event) + Send + Sync + 'static,
+    {
+        let sink: Box<dyn Fn(Event) + Send + Sync> = Box::new(sink);
+        Self(Target::Sink(Arc::from(sink)))
     }
 
     /// Emit an event.
@@ -74,6 +105,106 @@ impl<Event: Send> Emitter<Event> {
     /// This queues the event for processing by the runtime. Multiple threads
     /// can safely call this method concurrently via the lock-free channel.
     pub fn emit(&self, event: Event) {
-        self.0.send(event).ok();
+        match &self.0 {
+            Target::Channel(sender) => {
+                sender.send(event).ok();
+            }
+            Target::Sink(sink) => sink(event),
+        }
+    }
+
+    /// Emit an event, returning it if it could not be delivered.
+    ///
+    /// Unlike [`emit`](Self::emit), this reports when the receiving runtime has
+    /// been dropped, handing the undelivered event back to the caller.
+    pub fn try_emit(&self, event: Event) -> Result<(), Event> {
+        match &self.0 {
+            Target::Channel(sender) => sender.send(event).map_err(|error| error.into_inner()),
+            Target::Sink(sink) => {
+                sink(event);
+                Ok(())
+            }
+        }
+    }
+
+    /// Create an emitter of child events that converts them with `f` and emits
+    /// them through this emitter.
+    ///
+    /// Hand the result to a child component so its events are lifted into the
+    /// parent's event type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::Emitter;
+    ///
+    /// enum CounterEvent { Increment }
+    /// enum Event { Counter(CounterEvent) }
+    ///
+    /// fn counter_emitter(emitter: &Emitter<Event>) -> Emitter<CounterEvent> {
+    ///     emitter.contramap(Event::Counter)
+    /// }
+    /// ```
+    pub fn contramap<Child, F>(&self, f: F) -> Emitter<Child>
+    where
+        Event: 'static,
+        Child: Send,
+        F: Fn(Child) -> Event + Send + Sync + 'static,
+    {
+        let emitter = self.clone();
+        Emitter::from_sink(move |event| emitter.emit(f(event)))
+    }
+
+    /// Convert this emitter into a [`futures::Sink`] of events.
+    ///
+    /// Only available with the `futures` feature. See [`EmitterSink`].
+    #[cfg(feature = "futures")]
+    pub fn into_sink(self) -> EmitterSink<Event> {
+        EmitterSink(self)
+    }
+}
+
+/// [`futures::Sink`] that emits every item it receives.
+///
+/// Only available with the `futures` feature.
+///
+/// Created with [`Emitter::into_sink`], this lets a `futures::Stream` be piped
+/// straight into a runtime's event queue. Sending never fails and never waits.
+///
+/// # Example
+///
+/// ```rust
+/// use futures::{stream, StreamExt};
+/// use oxide_mvu::Emitter;
+///
+/// enum Event { Message(String) }
+///
+/// async fn pipe_messages(emitter: Emitter<Event>) {
+///     let messages = stream::iter(vec!["hello".to_string(), "world".to_string()]);
+///     let _ = messages.map(|message| Ok(Event::Message(message))).forward(emitter.into_sink()).await;
+/// }
+/// ```
+#[cfg(feature = "futures")]
+pub struct EmitterSink<Event: Send>(Emitter<Event>);
+
+#[cfg(feature = "futures")]
+impl<Event: Send> futures::Sink<Event> for EmitterSink<Event> {
+    type Error = Infallible;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, event: Event) -> Result<(), Infallible> {
+        self.0.emit(event);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
     }
 }
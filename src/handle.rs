@@ -0,0 +1,787 @@
+//! Handle for interacting with a runtime from outside the event loop.
+
+#[cfg(feature = "no_std")]
+use alloc::boxed::Box;
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+use core::future::Future;
+use core::time::Duration;
+#[cfg(not(feature = "no_std"))]
+use std::sync::{Condvar, Mutex as StdMutex, PoisonError};
+#[cfg(not(feature = "no_std"))]
+use std::time::Instant;
+
+use flume::{Receiver, Sender};
+use portable_atomic::{AtomicUsize, Ordering};
+use portable_atomic_util::{Arc, Weak};
+use spin::Mutex;
+
+use crate::Emitter;
+
+/// Per-channel emission counters shared by a runtime and its handles.
+pub(crate) type ChannelCounters = Arc<Mutex<Vec<(&'static str, Arc<AtomicUsize>)>>>;
+
+/// Out-of-band instruction sent from a handle to the runtime's event loop.
+///
+/// Commands are processed ahead of queued events.
+pub(crate) enum Command<Event, Model> {
+    /// Modify the model in place, then re-render it.
+    UpdateModel(Box<dyn FnOnce(&mut Model) + Send>),
+    /// Render the current model, sent when a scheduled render callback runs.
+    Render,
+    /// Register an observer of committed models.
+    Observe(ModelObserver<Model>),
+    /// Stop rendering model changes until rendering is resumed.
+    SuspendRendering,
+    /// Resume rendering and render the current model.
+    ResumeRendering,
+    /// Register a hook run for each processed event until it returns `true`.
+    OnceEvent(EventHook<Event>),
+    /// Emit the tick event, process every queued event and render once.
+    Tick(Duration),
+}
+
+/// Hook invoked with each processed event, returning whether it is done.
+pub(crate) type EventHook<Event> = Box<dyn FnMut(&Event) -> bool + Send>;
+
+/// Callback invoked with every model committed by the runtime, returning
+/// whether it should keep observing.
+pub(crate) type ModelObserver<Model> = Box<dyn FnMut(&Model) -> bool + Send>;
+
+/// Change applied to a model in place, bypassing [`MvuLogic::update`].
+///
+/// Used with [`RuntimeHandle::apply_diff`] for syncing external state, such as
+/// incremental updates from an authoritative server, without replacing the whole model.
+///
+/// [`MvuLogic::update`]: crate::MvuLogic::update
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::StateDiff;
+///
+/// struct Model { score: u32, name: String }
+///
+/// struct ScoreChanged(u32);
+///
+/// impl StateDiff<Model> for ScoreChanged {
+///     fn apply(&self, model: &mut Model) {
+///         model.score = self.0;
+///     }
+/// }
+/// ```
+pub trait StateDiff<Model> {
+    /// Apply this change to the model.
+    fn apply(&self, model: &mut Model);
+}
+
+/// Handle to a running runtime.
+///
+/// Obtained from [`MvuRuntime::handle`](crate::MvuRuntime::handle) (or
+/// [`TestMvuDriver::handle`](crate::TestMvuDriver::handle) in tests), a handle
+/// remains usable after the runtime has been moved into its event loop.
+///
+/// Handles are cheap to clone and can be shared across threads.
+///
+/// # Ordering
+///
+/// Apart from emitting events, handle methods send commands to the event loop
+/// on a queue of their own, and the event loop handles every pending command
+/// before the next queued event. Commands therefore **overtake events emitted
+/// before them**: `emitter.emit(a); handle.set_model(m)` commits `m` and then
+/// processes `a` on top of it, and `emitter.emit(a); handle.suspend_rendering()`
+/// suppresses the render of `a`. When the order matters, let the runtime settle
+/// in between, e.g. with `wait_idle`.
+pub struct RuntimeHandle<Event: Send, Model> {
+    emitter: Emitter<Event>,
+    /// Weak so that a live handle doesn't keep the event channel connected
+    /// after the runtime has been dropped.
+    event_receiver: Weak<Receiver<Event>>,
+    command_sender: Sender<Command<Event, Model>>,
+    channel_counters: ChannelCounters,
+    #[cfg(not(feature = "no_std"))]
+    idle: Arc<IdleSignal>,
+}
+
+impl<Event: Send, Model> Clone for RuntimeHandle<Event, Model> {
+    fn clone(&self) -> Self {
+        Self {
+            emitter: self.emitter.clone(),
+            event_receiver: self.event_receiver.clone(),
+            command_sender: self.command_sender.clone(),
+            channel_counters: self.channel_counters.clone(),
+            #[cfg(not(feature = "no_std"))]
+            idle: self.idle.clone(),
+        }
+    }
+}
+
+impl<Event: Send + 'static, Model> RuntimeHandle<Event, Model> {
+    /// Create a new handle from the runtime's channels.
+    pub(crate) fn new(
+        emitter: Emitter<Event>,
+        event_receiver: Weak<Receiver<Event>>,
+        command_sender: Sender<Command<Event, Model>>,
+        channel_counters: ChannelCounters,
+        #[cfg(not(feature = "no_std"))] idle: Arc<IdleSignal>,
+    ) -> Self {
+        Self {
+            emitter,
+            event_receiver,
+            command_sender,
+            channel_counters,
+            #[cfg(not(feature = "no_std"))]
+            idle,
+        }
+    }
+
+    /// Drain and return all queued events without processing them.
+    ///
+    /// The returned events are removed from the queue and will **not** be
+    /// processed by the runtime. This is useful for inspecting a stuck queue
+    /// or handing pending work off to another runtime.
+    ///
+    /// Returns an empty list once the runtime has been dropped.
+    pub fn take_pending(&self) -> Vec<Event> {
+        match self.event_receiver.upgrade() {
+            Some(event_receiver) => event_receiver.drain().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Replace the model wholesale and re-render, bypassing [`MvuLogic::update`].
+    ///
+    /// This is an out-of-band override for cases such as receiving authoritative
+    /// state from a server. The new model is committed and rendered by the event
+    /// loop before any queued events are processed.
+    ///
+    /// **Overtakes queued events**: events emitted before this call are applied
+    /// on top of `model`. See [ordering](Self#ordering).
+    ///
+    /// No event is involved, so any invariants normally enforced by `update` are
+    /// **not** checked. Only use this from trusted callers.
+    ///
+    /// [`MvuLogic::update`]: crate::MvuLogic::update
+    pub fn set_model(&self, model: Model)
+    where
+        Model: Send + 'static,
+    {
+        self.send_command(Command::UpdateModel(Box::new(move |current| {
+            *current = model;
+        })));
+    }
+
+    /// Apply a [`StateDiff`] to the model in place and re-render.
+    ///
+    /// Like [`set_model`](Self::set_model), this bypasses [`MvuLogic::update`] and
+    /// is applied by the event loop before any queued events. Prefer it over
+    /// `set_model` when only a small part of a large model changes.
+    ///
+    /// **Overtakes queued events**: events emitted before this call are applied
+    /// after the diff. See [ordering](Self#ordering).
+    ///
+    /// No event is involved, so any invariants normally enforced by `update` are
+    /// **not** checked. Only use this from trusted callers.
+    ///
+    /// [`MvuLogic::update`]: crate::MvuLogic::update
+    pub fn apply_diff<D>(&self, diff: D)
+    where
+        D: StateDiff<Model> + Send + 'static,
+    {
+        self.send_command(Command::UpdateModel(Box::new(move |model| {
+            diff.apply(model);
+        })));
+    }
+
+    /// Stop rendering while continuing to process events.
+    ///
+    /// Events are still passed to `update` and their effects still run, but the
+    /// resulting models aren't rendered until [`resume_rendering`](Self::resume_rendering)
+    /// is called. This is useful for bulk work such as importing many records,
+    /// where only the final state is worth rendering. Unlike not polling the
+    /// runtime at all, the model keeps up with emitted events.
+    ///
+    /// **Overtakes queued events**: models produced by events emitted before
+    /// this call aren't rendered either. See [ordering](Self#ordering).
+    pub fn suspend_rendering(&self) {
+        self.send_command(Command::SuspendRendering);
+    }
+
+    /// Resume rendering after [`suspend_rendering`](Self::suspend_rendering).
+    ///
+    /// Unlike other commands, this doesn't overtake queued events: events
+    /// queued before this call are processed first, still without rendering.
+    /// The resulting model is then rendered once, followed by every later
+    /// change as usual. See [ordering](Self#ordering).
+    pub fn resume_rendering(&self) {
+        self.send_command(Command::ResumeRendering);
+    }
+
+    /// Advance the program by a fixed timestep.
+    ///
+    /// Once the event loop picks it up, the event configured with
+    /// [`MvuRuntime::with_tick_event`](crate::MvuRuntime::with_tick_event) is
+    /// emitted for `dt`, behind the events already queued. Every queued event,
+    /// including the tick, is then processed and the settled model is rendered
+    /// once. Use
+    /// [`MvuRuntime::tick`](crate::MvuRuntime::tick) instead to tick
+    /// synchronously from a host that owns the runtime.
+    pub fn tick(&self, dt: Duration) {
+        self.send_command(Command::Tick(dt));
+    }
+
+    /// Group several out-of-band changes so they are rendered once.
+    ///
+    /// Rendering is suspended while `f` runs and resumed afterwards, so changes
+    /// made in `f` through [`set_model`](Self::set_model),
+    /// [`apply_diff`](Self::apply_diff) or emitted events result in a single
+    /// render of the final model. Events still queued when the transaction
+    /// starts are folded into the same render. Rendering is resumed even if it
+    /// had already been suspended with
+    /// [`suspend_rendering`](Self::suspend_rendering) before the transaction.
+    ///
+    /// Returns the value returned by `f`.
+    pub fn transaction<R>(&self, f: impl FnOnce(&Self) -> R) -> R {
+        self.suspend_rendering();
+        let result = f(self);
+        self.resume_rendering();
+        result
+    }
+
+    /// Observe the current model and every model committed after it.
+    ///
+    /// Once the event loop picks up the subscription, `observer` is called
+    /// immediately with the current model, then again each time a new model is
+    /// committed, whether by an event, [`set_model`](Self::set_model) or
+    /// [`apply_diff`](Self::apply_diff). Observers run after the committed model
+    /// has been rendered, in subscription order.
+    ///
+    /// **Overtakes queued events**: models produced by events emitted before
+    /// this call are observed too. See [ordering](Self#ordering).
+    ///
+    /// This is the hook for tools such as a live model inspector.
+    pub fn subscribe_history<F>(&self, mut observer: F)
+    where
+        F: FnMut(&Model) + Send + 'static,
+    {
+        self.send_command(Command::Observe(Box::new(move |model: &Model| {
+            observer(model);
+            true
+        })));
+    }
+
+    /// Observe committed models for as long as `observer` is alive elsewhere.
+    ///
+    /// Behaves like [`subscribe_history`](Self::subscribe_history), except the
+    /// runtime only holds a weak reference. Once every strong reference to the
+    /// observer has been dropped, it is removed on the next commit instead of
+    /// being kept alive forever, so subscribers can come and go without leaking.
+    ///
+    /// The observer is held through [`Weak`](crate::Weak), re-exported by this
+    /// crate along with [`Arc`](crate::Arc).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::{Arc, RuntimeHandle};
+    ///
+    /// fn inspect(handle: &RuntimeHandle<(), u32>) -> Arc<dyn Fn(&u32) + Send + Sync> {
+    ///     let observer: Box<dyn Fn(&u32) + Send + Sync> = Box::new(|count| println!("{count}"));
+    ///     let observer = Arc::from(observer);
+    ///     handle.subscribe_model_weak(Arc::downgrade(&observer));
+    ///     // Observation stops once the returned Arc is dropped
+    ///     observer
+    /// }
+    /// ```
+    pub fn subscribe_model_weak(&self, observer: Weak<dyn Fn(&Model) + Send + Sync>)
+    where
+        Model: 'static,
+    {
+        self.send_command(Command::Observe(Box::new(
+            move |model: &Model| match observer.upgrade() {
+                Some(observer) => {
+                    observer(model);
+                    true
+                }
+                None => false,
+            },
+        )));
+    }
+
+    /// Run `callback` on the next processed event matching `predicate`.
+    ///
+    /// Once registration has been picked up by the event loop, each processed
+    /// event is checked with `predicate` just before it is passed to
+    /// [`MvuLogic::update`]. The first match is handed to `callback`, after
+    /// which the registration is removed. This suits transient waits such as
+    /// "continue once the next `DataLoaded` arrives" without adding state to
+    /// the model.
+    ///
+    /// **Overtakes queued events**: events emitted before this call but not
+    /// processed yet can match. See [ordering](Self#ordering).
+    ///
+    /// [`MvuLogic::update`]: crate::MvuLogic::update
+    pub fn once_event<P, F>(&self, predicate: P, callback: F)
+    where
+        P: Fn(&Event) -> bool + Send + 'static,
+        F: FnOnce(&Event) + Send + 'static,
+    {
+        let mut callback = Some(callback);
+        self.send_command(Command::OnceEvent(Box::new(move |event| {
+            if !predicate(event) {
+                return false;
+            }
+
+            if let Some(callback) = callback.take() {
+                callback(event);
+            }
+            true
+        })));
+    }
+
+    /// Emit a request event and wait for the reply sent through it.
+    ///
+    /// `make_event` receives a [`ResponseChannel`] to embed in the event. The
+    /// event is emitted immediately, and the returned future resolves with the
+    /// first value passed to [`ResponseChannel::reply`], typically by
+    /// [`MvuLogic::update`] when handling the event. This gives callers outside
+    /// the event loop a request/response flow, like the actor "ask" pattern.
+    ///
+    /// The future resolves to `None` if every copy of the channel is dropped
+    /// without a reply, e.g. because the runtime was dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::{ResponseChannel, RuntimeHandle};
+    ///
+    /// enum Event { CountRequested(ResponseChannel<i32>) }
+    ///
+    /// async fn current_count<Model>(handle: &RuntimeHandle<Event, Model>) -> Option<i32> {
+    ///     handle.ask(Event::CountRequested).await
+    /// }
+    /// ```
+    ///
+    /// [`MvuLogic::update`]: crate::MvuLogic::update
+    pub fn ask<R, F>(&self, make_event: F) -> impl Future<Output = Option<R>> + Send + 'static
+    where
+        R: Send + 'static,
+        F: FnOnce(ResponseChannel<R>) -> Event,
+    {
+        let (sender, receiver) = flume::bounded(1);
+        self.emitter.emit(make_event(ResponseChannel(sender)));
+        async move { receiver.recv_async().await.ok() }
+    }
+
+    /// Send a command to the event loop.
+    fn send_command(&self, command: Command<Event, Model>) {
+        self.command_sender.send(command).ok();
+    }
+
+    /// Get an emitter feeding the runtime's event queue.
+    ///
+    /// Events emitted through it aren't attributed to any
+    /// [`channel`](Self::channel).
+    pub fn emitter(&self) -> Emitter<Event> {
+        self.emitter.clone()
+    }
+
+    /// Get an emitter attributed to the named channel.
+    ///
+    /// All channels feed the same event queue, but every event emitted through
+    /// a channel increments that channel's counter, which can be read with
+    /// [`channel_counts`](Self::channel_counts). Requesting the same name more
+    /// than once returns emitters sharing a single counter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use oxide_mvu::{Emitter, Effect, MvuLogic, MvuRuntime, Renderer};
+    /// # #[derive(Clone)] enum Event { Tick }
+    /// # #[derive(Clone)] struct Model;
+    /// # struct Logic;
+    /// # impl MvuLogic<Event, Model, ()> for Logic {
+    /// #     fn init(&self, m: Model) -> (Model, Effect<Event>) { (m, Effect::none()) }
+    /// #     fn update(&self, _: Event, m: &Model) -> (Model, Effect<Event>) { (m.clone(), Effect::none()) }
+    /// #     fn view(&self, _: &Model, _: &Emitter<Event>) {}
+    /// # }
+    /// # struct NoopRenderer;
+    /// # impl Renderer<()> for NoopRenderer { fn render(&mut self, _: ()) {} }
+    /// let runtime = MvuRuntime::new(Model, Logic, NoopRenderer, |_| {});
+    /// let handle = runtime.handle();
+    ///
+    /// let timer = handle.channel("timer");
+    /// timer.emit(Event::Tick);
+    ///
+    /// assert_eq!(handle.channel_counts(), vec![("timer", 1)]);
+    /// ```
+    pub fn channel(&self, name: &'static str) -> Emitter<Event> {
+        let counter = {
+            let mut counters = self.channel_counters.lock();
+            match counters.iter().find(|(existing, _)| *existing == name) {
+                Some((_, counter)) => counter.clone(),
+                None => {
+                    let counter = Arc::new(AtomicUsize::new(0));
+                    counters.push((name, counter.clone()));
+                    counter
+                }
+            }
+        };
+
+        let emitter = self.emitter.clone();
+        Emitter::from_sink(move |event| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            emitter.emit(event);
+        })
+    }
+
+    /// Number of events emitted through each named channel so far.
+    ///
+    /// Channels are listed in the order they were first created.
+    pub fn channel_counts(&self) -> Vec<(&'static str, usize)> {
+        self.channel_counters
+            .lock()
+            .iter()
+            .map(|(name, counter)| (*name, counter.load(Ordering::Relaxed)))
+            .collect()
+    }
+
+    /// Block until the runtime has processed everything queued and its effects
+    /// have completed, or until `timeout` elapses.
+    ///
+    /// Only available without the `no_std` feature. Intended for tests and
+    /// coordinators of a runtime running on another thread, as an alternative
+    /// to polling with sleeps. The runtime must be driven by its event loop
+    /// ([`MvuRuntime::run`](crate::MvuRuntime::run) or
+    /// [`poll_once`](crate::MvuRuntime::poll_once)) for this to return.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Timeout`] if the runtime is still busy once `timeout` elapses.
+    #[cfg(not(feature = "no_std"))]
+    pub fn wait_idle(&self, timeout: Duration) -> Result<(), Timeout> {
+        let queues_empty = || {
+            let events_empty = match self.event_receiver.upgrade() {
+                Some(event_receiver) => event_receiver.is_empty(),
+                // The runtime was dropped, so no events are left to process
+                None => true,
+            };
+            events_empty && self.command_sender.is_empty()
+        };
+        if self.idle.wait(timeout, queues_empty) {
+            Ok(())
+        } else {
+            Err(Timeout)
+        }
+    }
+}
+
+/// Error returned by [`RuntimeHandle::wait_idle`] when the runtime didn't
+/// become idle in time.
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeout;
+
+#[cfg(not(feature = "no_std"))]
+impl core::fmt::Display for Timeout {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("timed out waiting for the runtime to become idle")
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for Timeout {}
+
+/// Work in progress in a runtime, shared with its handles for
+/// [`RuntimeHandle::wait_idle`].
+#[cfg(not(feature = "no_std"))]
+#[derive(Default)]
+pub(crate) struct IdleSignal {
+    activity: StdMutex<Activity>,
+    changed: Condvar,
+}
+
+#[cfg(not(feature = "no_std"))]
+#[derive(Default)]
+struct Activity {
+    processing: bool,
+    effects_in_flight: usize,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl IdleSignal {
+    /// Record whether the event loop is processing, as opposed to waiting for work.
+    pub(crate) fn set_processing(&self, processing: bool) {
+        self.activity().processing = processing;
+        if !processing {
+            self.changed.notify_all();
+        }
+    }
+
+    /// Record that an effect has been spawned.
+    pub(crate) fn effect_started(&self) {
+        self.activity().effects_in_flight += 1;
+    }
+
+    /// Record that a spawned effect has completed or been dropped.
+    pub(crate) fn effect_finished(&self) {
+        self.activity().effects_in_flight -= 1;
+        self.changed.notify_all();
+    }
+
+    /// Wait until nothing is in progress and `queues_empty` holds, returning
+    /// `false` if `timeout` elapses first.
+    fn wait(&self, timeout: Duration, queues_empty: impl Fn() -> bool) -> bool {
+        let deadline = Instant::now() + timeout;
+        let mut activity = self.activity();
+        loop {
+            if !activity.processing && activity.effects_in_flight == 0 && queues_empty() {
+                return true;
+            }
+
+            let Some(remaining) = deadline.checked_duration_since(Instant::now()) else {
+                return false;
+            };
+            activity = self
+                .changed
+                .wait_timeout(activity, remaining)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+
+    fn activity(&self) -> std::sync::MutexGuard<'_, Activity> {
+        self.activity.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Marks a spawned effect as in flight until dropped.
+#[cfg(not(feature = "no_std"))]
+pub(crate) struct EffectInFlight(Arc<IdleSignal>);
+
+#[cfg(not(feature = "no_std"))]
+impl EffectInFlight {
+    pub(crate) fn new(idle: &Arc<IdleSignal>) -> Self {
+        idle.effect_started();
+        Self(idle.clone())
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl Drop for EffectInFlight {
+    fn drop(&mut self) {
+        self.0.effect_finished();
+    }
+}
+
+/// Channel for replying to a request made with [`RuntimeHandle::ask`].
+///
+/// Embedded in the request event. Clones share the same request, and only the
+/// first reply is delivered.
+pub struct ResponseChannel<R>(Sender<R>);
+
+impl<R> Clone for ResponseChannel<R> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<R> core::fmt::Debug for ResponseChannel<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ResponseChannel").finish_non_exhaustive()
+    }
+}
+
+impl<R> ResponseChannel<R> {
+    /// Resolve the waiting [`ask`](RuntimeHandle::ask) with `value`.
+    ///
+    /// Does nothing if the request was already answered or the asker stopped
+    /// waiting.
+    pub fn reply(&self, value: R) {
+        self.0.try_send(value).ok();
+    }
+}
+
+/// Read-only view of a runtime's current model.
+///
+/// Created from a [`RuntimeHandle`], a `ModelAccess` keeps a copy of the latest
+/// committed model, refreshed by a model observer. It is cheap to clone and can
+/// be captured by effects that need to consult the model at execution time
+/// rather than when they were created, see [`Effect::select`]. Once every clone
+/// has been dropped, the observer is removed on the next commit.
+///
+/// The copy is only populated once the event loop has picked up the
+/// subscription, so reads return `None` before that.
+///
+/// [`Effect::select`]: crate::Effect::select
+pub struct ModelAccess<Model> {
+    current: Arc<Mutex<Snapshot<Model>>>,
+}
+
+/// Latest model observed by a [`ModelAccess`].
+struct Snapshot<Model> {
+    model: Option<Model>,
+    version: u64,
+}
+
+impl<Model> Clone for ModelAccess<Model> {
+    fn clone(&self) -> Self {
+        Self {
+            current: self.current.clone(),
+        }
+    }
+}
+
+impl<Model: Clone + Send + 'static> ModelAccess<Model> {
+    /// Track the model of the runtime owning `handle`.
+    pub fn new<Event: Send + 'static>(handle: &RuntimeHandle<Event, Model>) -> Self {
+        let current = Arc::new(Mutex::new(Snapshot {
+            model: None,
+            version: 0,
+        }));
+
+        // Held weakly so the observer is removed once every clone is dropped,
+        // instead of snapshotting models nobody can read
+        let latest = Arc::downgrade(&current);
+        handle.send_command(Command::Observe(Box::new(move |model: &Model| {
+            let Some(latest) = latest.upgrade() else {
+                return false;
+            };
+            let mut snapshot = latest.lock();
+            snapshot.model = Some(model.clone());
+            snapshot.version += 1;
+            true
+        })));
+
+        Self { current }
+    }
+
+    /// Read the current model, or `None` if it hasn't been observed yet.
+    pub fn read<R>(&self, f: impl FnOnce(&Model) -> R) -> Option<R> {
+        self.current.lock().model.as_ref().map(f)
+    }
+
+    /// Version of the current model.
+    ///
+    /// The version changes every time a new model is committed, so comparing
+    /// versions tells whether the model was replaced in between. It is `0` until
+    /// the first model has been observed.
+    ///
+    /// Called from `update`, this is the version of the model being updated, not
+    /// of the one `update` returns. [`Effect::if_version`] reads the version
+    /// once the returned model has been committed instead.
+    ///
+    /// [`Effect::if_version`]: crate::Effect::if_version
+    pub fn version(&self) -> u64 {
+        self.current.lock().version
+    }
+}
+
+/// Sends events into another runtime's event queue.
+///
+/// A bridge is created from the target runtime's [`RuntimeHandle`] and used with
+/// [`Effect::forward_to`](crate::Effect::forward_to) so that one MVU program can
+/// dispatch events to another without sharing raw emitters.
+///
+/// Bridges are `Send + Sync` and cheap to clone. Events forwarded after the target
+/// runtime has been dropped are silently discarded, so the target must outlive any
+/// effects forwarding to it for those events to be processed.
+pub struct Bridge<Event: Send> {
+    emitter: Emitter<Event>,
+}
+
+impl<Event: Send> Clone for Bridge<Event> {
+    fn clone(&self) -> Self {
+        Self {
+            emitter: self.emitter.clone(),
+        }
+    }
+}
+
+impl<Event: Send + 'static> Bridge<Event> {
+    /// Create a bridge into the runtime owning `handle`.
+    pub fn new<Model>(handle: &RuntimeHandle<Event, Model>) -> Self {
+        Self {
+            emitter: handle.emitter.clone(),
+        }
+    }
+
+    /// Emit an event into the target runtime's queue.
+    pub fn emit(&self, event: Event) {
+        self.emitter.emit(event);
+    }
+
+    /// Emit an event into the target runtime's queue, returning it if the
+    /// target runtime has been dropped.
+    pub fn try_emit(&self, event: Event) -> Result<(), Event> {
+        self.emitter.try_emit(event)
+    }
+}
+
+/// Sends every event to several runtimes at once.
+///
+/// Useful for global events that multiple independent MVU programs react to,
+/// such as a theme or locale change. Each registered runtime receives its own
+/// clone of the event. Runtimes that have been dropped are skipped.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{BroadcastEmitter, RuntimeHandle};
+///
+/// #[derive(Clone)]
+/// enum Event { ThemeChanged }
+///
+/// fn broadcast_theme<A, B>(sidebar: &RuntimeHandle<Event, A>, editor: &RuntimeHandle<Event, B>) {
+///     let mut broadcast = BroadcastEmitter::new();
+///     broadcast.add(sidebar);
+///     broadcast.add(editor);
+///
+///     broadcast.emit(Event::ThemeChanged);
+/// }
+/// ```
+pub struct BroadcastEmitter<Event: Send> {
+    targets: Vec<Bridge<Event>>,
+}
+
+impl<Event: Send> Clone for BroadcastEmitter<Event> {
+    fn clone(&self) -> Self {
+        Self {
+            targets: self.targets.clone(),
+        }
+    }
+}
+
+impl<Event: Send + 'static> Default for BroadcastEmitter<Event> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Event: Send + 'static> BroadcastEmitter<Event> {
+    /// Create a broadcast emitter with no targets.
+    pub fn new() -> Self {
+        Self {
+            targets: Vec::new(),
+        }
+    }
+
+    /// Register the runtime owning `handle` to receive broadcast events.
+    pub fn add<Model>(&mut self, handle: &RuntimeHandle<Event, Model>) {
+        self.targets.push(Bridge::new(handle));
+    }
+
+    /// Send a clone of `event` to every registered runtime.
+    ///
+    /// Runtimes that have been dropped are skipped.
+    pub fn emit(&self, event: Event)
+    where
+        Event: Clone,
+    {
+        for target in &self.targets {
+            // A dropped runtime can't receive events, so there is nothing to do
+            let _ = target.try_emit(event.clone());
+        }
+    }
+}
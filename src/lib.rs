@@ -92,21 +92,56 @@
 extern crate alloc;
 
 // Module declarations
+mod clock;
 mod effect;
 mod emitter;
+mod handle;
 mod logic;
+mod persistence;
+mod prerender;
 mod renderer;
 mod runtime;
 
 // Public re-exports
-pub use effect::Effect;
+pub use clock::Clock;
+#[cfg(not(feature = "no_std"))]
+pub use clock::SystemClock;
+pub use effect::{Effect, EffectScope};
 pub use emitter::Emitter;
-pub use logic::MvuLogic;
-pub use renderer::Renderer;
-pub use runtime::{MvuRuntime, Spawner};
+#[cfg(feature = "futures")]
+pub use emitter::EmitterSink;
+#[cfg(not(feature = "no_std"))]
+pub use handle::Timeout;
+pub use handle::{
+    Bridge, BroadcastEmitter, ModelAccess, ResponseChannel, RuntimeHandle, StateDiff,
+};
+pub use logic::{
+    BatchMvuLogic, DispatchLogic, FnLogic, Lens, MvuLogic, Routed, RouterLogic, ViewComposer,
+};
+pub use persistence::Persistence;
+/// Reference-counted pointers used in the public API, such as by
+/// [`RuntimeHandle::subscribe_model_weak`], re-exported so callers don't need
+/// a matching `portable-atomic-util` dependency.
+pub use portable_atomic_util::{Arc, Weak};
+pub use prerender::prerender;
+#[cfg(not(feature = "no_std"))]
+pub use renderer::FallbackRenderer;
+pub use renderer::{Renderer, RingBufferRenderer, ThrottledRenderer};
+pub use runtime::{
+    Coalescer, CooperativeSpawner, InitRenderMode, MvuRuntime, PropsTransform, Spawner,
+};
+#[cfg(not(feature = "no_std"))]
+pub use runtime::{ErrorAction, ErrorBoundary, PanicPolicy, RuntimeError};
 
 // Test utilities (only available with 'testing' feature or during tests)
 #[cfg(any(test, feature = "testing"))]
-pub use renderer::TestRenderer;
+pub use clock::TestClock;
 #[cfg(any(test, feature = "testing"))]
-pub use runtime::{create_test_spawner, TestMvuDriver, TestMvuRuntime};
+pub use effect::{EffectDescription, EffectMatcher};
+#[cfg(any(test, feature = "testing"))]
+pub use renderer::{DiffTestRenderer, TestRenderer};
+#[cfg(any(test, feature = "testing"))]
+pub use runtime::{
+    block_on, create_test_spawner, dry_update, inspect_init, replay, RenderStream, TestMvuDriver,
+    TestMvuRuntime,
+};
@@ -1,5 +1,12 @@
 //! Application logic trait defining the MVU contract.
 
+#[cfg(feature = "no_std")]
+use alloc::boxed::Box;
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+use portable_atomic_util::Arc;
+
 use crate::{Effect, Emitter};
 
 /// Application logic trait defining the MVU contract.
@@ -58,4 +65,767 @@ pub trait MvuLogic<Event: Send, Model, Props> {
     ///
     /// Props derived from the model, ready for rendering via [`Renderer::render`](crate::Renderer::render).
     fn view(&self, model: &Model, emitter: &Emitter<Event>) -> Props;
+
+    /// Derive a key identifying the parts of the model that affect Props.
+    ///
+    /// When this returns `Some(key)` and the key is equal to the one computed
+    /// for the previously rendered model, the runtime skips both
+    /// [`view`](Self::view) and rendering for that update. Use this when
+    /// `view` is expensive and only depends on a subset of the model.
+    ///
+    /// The default implementation returns `None`, so `view` runs after every update.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model about to be rendered
+    fn view_key(&self, _model: &Model) -> Option<u64> {
+        None
+    }
+
+    /// Name and key of each model field [`view`](Self::view) reads.
+    ///
+    /// Used for render-cause diagnostics with
+    /// [`MvuRuntime::with_render_cause`](crate::MvuRuntime::with_render_cause):
+    /// before each render, the runtime compares these keys with those of the
+    /// previous render and reports the names whose key changed. A key is any
+    /// value that changes when the field does, such as the field itself or a hash
+    /// of it.
+    ///
+    /// The default implementation declares no fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model about to be rendered
+    fn view_deps(&self, _model: &Model) -> Vec<(&'static str, u64)> {
+        Vec::new()
+    }
+}
+
+/// [`MvuLogic`] that can also reduce a whole batch of events in one call.
+///
+/// Useful for logic that naturally processes groups of events, such as a
+/// transaction of edits. When enabled via
+/// [`MvuRuntime::with_batch_update`](crate::MvuRuntime::with_batch_update), the
+/// runtime drains every event queued at the time it wakes up, runs the
+/// [`Coalescer`](crate::Coalescer) if one is registered, and hands the batch to
+/// [`update_batch`](Self::update_batch) instead of calling
+/// [`update`](MvuLogic::update) once per event. The model is then rendered once
+/// for the whole batch.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{BatchMvuLogic, Effect, Emitter, MvuLogic};
+///
+/// enum Event { Add(i32) }
+///
+/// struct Counter;
+///
+/// impl MvuLogic<Event, i32, i32> for Counter {
+///     fn init(&self, model: i32) -> (i32, Effect<Event>) { (model, Effect::none()) }
+///     fn update(&self, event: Event, model: &i32) -> (i32, Effect<Event>) {
+///         self.update_batch(vec![event], model)
+///     }
+///     fn view(&self, model: &i32, _emitter: &Emitter<Event>) -> i32 { *model }
+/// }
+///
+/// impl BatchMvuLogic<Event, i32, i32> for Counter {
+///     fn update_batch(&self, events: Vec<Event>, model: &i32) -> (i32, Effect<Event>) {
+///         let total: i32 = events.into_iter().map(|Event::Add(n)| n).sum();
+///         (model + total, Effect::none())
+///     }
+/// }
+/// ```
+pub trait BatchMvuLogic<Event: Send, Model, Props>: MvuLogic<Event, Model, Props> {
+    /// Reduce a batch of events to an updated model and side effects.
+    ///
+    /// # Arguments
+    ///
+    /// * `events` - The queued events, in the order they were emitted
+    /// * `model` - The current model state
+    ///
+    /// # Returns
+    ///
+    /// A tuple of `(Model, Effect<Event>)` containing the model after the whole
+    /// batch and any effects to process.
+    fn update_batch(&self, events: Vec<Event>, model: &Model) -> (Model, Effect<Event>);
+}
+
+/// [`MvuLogic`] built from three closures.
+///
+/// Saves defining a logic struct for small programs, examples and tests. The
+/// closures have the same signatures as [`MvuLogic::init`], [`MvuLogic::update`]
+/// and [`MvuLogic::view`]; [`MvuLogic::view_key`] and [`MvuLogic::view_deps`]
+/// keep their defaults.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{Effect, Emitter, FnLogic};
+///
+/// enum Event { Increment }
+///
+/// let logic = FnLogic::new(
+///     |model: i32| (model, Effect::<Event>::none()),
+///     |event: Event, model: &i32| match event {
+///         Event::Increment => (model + 1, Effect::<Event>::none()),
+///     },
+///     |model: &i32, _emitter: &Emitter<Event>| format!("count: {}", model),
+/// );
+/// ```
+pub struct FnLogic<Init, Update, View> {
+    init: Init,
+    update: Update,
+    view: View,
+}
+
+impl<Init, Update, View> FnLogic<Init, Update, View> {
+    /// Create a logic from its `init`, `update` and `view` functions.
+    pub fn new(init: Init, update: Update, view: View) -> Self {
+        Self { init, update, view }
+    }
+}
+
+impl<Event, Model, Props, Init, Update, View> MvuLogic<Event, Model, Props>
+    for FnLogic<Init, Update, View>
+where
+    Event: Send,
+    Init: Fn(Model) -> (Model, Effect<Event>),
+    Update: Fn(Event, &Model) -> (Model, Effect<Event>),
+    View: Fn(&Model, &Emitter<Event>) -> Props,
+{
+    fn init(&self, model: Model) -> (Model, Effect<Event>) {
+        (self.init)(model)
+    }
+
+    fn update(&self, event: Event, model: &Model) -> (Model, Effect<Event>) {
+        (self.update)(event, model)
+    }
+
+    fn view(&self, model: &Model, emitter: &Emitter<Event>) -> Props {
+        (self.view)(model, emitter)
+    }
+}
+
+/// [`MvuLogic`] whose `update` is split across several feature modules.
+///
+/// Useful when one runtime's `Event` enum combines the events of independent
+/// features. Each feature registers an update function together with a matcher
+/// selecting the events it handles. Events are dispatched to the first route
+/// whose matcher accepts them; unmatched events, as well as `init`, `view` and
+/// `view_key`, are handled by the wrapped base logic.
+///
+/// Routes registered with [`route`](Self::route) receive the whole model.
+/// Routes registered with [`route_lens`](Self::route_lens) only see the part of
+/// the model selected by a [`Lens`], so each feature can be written against its
+/// own sub-model. A feature's view can be focused the same way with
+/// [`ViewComposer::child`].
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{DispatchLogic, Effect, Emitter, FnLogic};
+///
+/// #[derive(Clone, Default)]
+/// struct Model { count: i32, dark_mode: bool }
+///
+/// enum Event { Increment, ToggleTheme, Reset }
+///
+/// let base = FnLogic::new(
+///     |model: Model| (model, Effect::<Event>::none()),
+///     |_event: Event, _model: &Model| (Model::default(), Effect::<Event>::none()),
+///     |model: &Model, _emitter: &Emitter<Event>| model.count,
+/// );
+///
+/// let logic = DispatchLogic::new(base)
+///     .route(
+///         |event| matches!(event, Event::Increment),
+///         |_event, model: &Model| (Model { count: model.count + 1, ..model.clone() }, Effect::none()),
+///     )
+///     .route(
+///         |event| matches!(event, Event::ToggleTheme),
+///         |_event, model: &Model| (Model { dark_mode: !model.dark_mode, ..model.clone() }, Effect::none()),
+///     );
+/// ```
+pub struct DispatchLogic<Event: Send, Model, Base> {
+    base: Base,
+    routes: Vec<Route<Event, Model>>,
+}
+
+/// An update function of a [`DispatchLogic`] and the events it handles.
+struct Route<Event: Send, Model> {
+    matches: Box<dyn Fn(&Event) -> bool>,
+    update: Box<UpdateFn<Event, Model>>,
+}
+
+/// Update function handling a subset of events.
+type UpdateFn<Event, Model> = dyn Fn(Event, &Model) -> (Model, Effect<Event>);
+
+impl<Event: Send, Model, Base> DispatchLogic<Event, Model, Base> {
+    /// Create a dispatcher falling back to `base` for unmatched events.
+    pub fn new(base: Base) -> Self {
+        Self {
+            base,
+            routes: Vec::new(),
+        }
+    }
+
+    /// Handle events accepted by `matches` with `update`.
+    ///
+    /// Routes are tried in registration order.
+    pub fn route<M, U>(mut self, matches: M, update: U) -> Self
+    where
+        M: Fn(&Event) -> bool + 'static,
+        U: Fn(Event, &Model) -> (Model, Effect<Event>) + 'static,
+    {
+        self.routes.push(Route {
+            matches: Box::new(matches),
+            update: Box::new(update),
+        });
+        self
+    }
+
+    /// Handle events accepted by `matches` with `update`, focused on the part
+    /// of the model selected by `lens`.
+    ///
+    /// `update` receives the current part and returns its replacement, which is
+    /// written back into a copy of the model. Routes are tried in registration
+    /// order, together with those registered with [`route`](Self::route).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxide_mvu::{DispatchLogic, Effect, Emitter, FnLogic, Lens};
+    ///
+    /// #[derive(Clone)]
+    /// struct Model { count: i32, dark_mode: bool }
+    ///
+    /// enum Event { Increment, ToggleTheme }
+    ///
+    /// let base = FnLogic::new(
+    ///     |model: Model| (model, Effect::<Event>::none()),
+    ///     |_event: Event, model: &Model| (model.clone(), Effect::<Event>::none()),
+    ///     |model: &Model, _emitter: &Emitter<Event>| model.count,
+    /// );
+    ///
+    /// let count = Lens::new(|model: &Model| &model.count, |model, count| model.count = count);
+    /// let logic = DispatchLogic::new(base)
+    ///     .route_lens(|event| matches!(event, Event::Increment), count, |_event, count| {
+    ///         (count + 1, Effect::none())
+    ///     });
+    /// ```
+    pub fn route_lens<M, Part, U>(self, matches: M, lens: Lens<Model, Part>, update: U) -> Self
+    where
+        Model: Clone + 'static,
+        Part: 'static,
+        M: Fn(&Event) -> bool + 'static,
+        U: Fn(Event, &Part) -> (Part, Effect<Event>) + 'static,
+    {
+        self.route(matches, move |event, model: &Model| {
+            let (part, effect) = update(event, lens.get(model));
+            let mut model = model.clone();
+            lens.set(&mut model, part);
+            (model, effect)
+        })
+    }
+}
+
+impl<Event, Model, Props, Base> MvuLogic<Event, Model, Props> for DispatchLogic<Event, Model, Base>
+where
+    Event: Send,
+    Base: MvuLogic<Event, Model, Props>,
+{
+    fn init(&self, model: Model) -> (Model, Effect<Event>) {
+        self.base.init(model)
+    }
+
+    fn update(&self, event: Event, model: &Model) -> (Model, Effect<Event>) {
+        match self.routes.iter().find(|route| (route.matches)(&event)) {
+            Some(route) => (route.update)(event, model),
+            None => self.base.update(event, model),
+        }
+    }
+
+    fn view(&self, model: &Model, emitter: &Emitter<Event>) -> Props {
+        self.base.view(model, emitter)
+    }
+
+    fn view_key(&self, model: &Model) -> Option<u64> {
+        self.base.view_key(model)
+    }
+
+    fn view_deps(&self, model: &Model) -> Vec<(&'static str, u64)> {
+        self.base.view_deps(model)
+    }
+}
+
+/// [`MvuLogic`] that delegates to the child logic of the model's current route.
+///
+/// Suits apps made of several screens, each implemented as its own
+/// `MvuLogic` with its own event, model and Props types. The parent model holds
+/// a route discriminant, read with the `route_of` function, plus one sub-model
+/// per child, selected with a [`Lens`]. Each [`child`](Self::child) is
+/// registered for one route:
+///
+/// - `init` runs the base logic's `init`, then that of the child active on the
+///   resulting route. The child's effect is lifted back with [`Effect::map`].
+///   Children of other routes are not initialized.
+/// - `update` hands events to the active child when they unwrap into its event
+///   type. The child's effect is lifted back with [`Effect::map`].
+/// - `view` renders the active child against an emitter lifted with
+///   [`Emitter::contramap`], then converts its Props into the parent's.
+/// - `view_key` and `view_deps` are those of the active child, computed on its
+///   sub-model. Keys are mixed with the route, so switching routes always
+///   renders.
+///
+/// Only the active child's logic runs. Events it doesn't accept, including
+/// those of inactive children, go to the base logic, which also handles
+/// navigation events and the view of routes without a child.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{Effect, Emitter, FnLogic, Lens, RouterLogic};
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Route { Home, Settings }
+///
+/// #[derive(Clone)]
+/// struct Model { route: Route, visits: u32 }
+///
+/// enum HomeEvent { Visited }
+///
+/// enum Event { Navigate(Route), Home(HomeEvent) }
+///
+/// let base = FnLogic::new(
+///     |model: Model| (model, Effect::<Event>::none()),
+///     |event: Event, model: &Model| match event {
+///         Event::Navigate(route) => (Model { route, ..model.clone() }, Effect::<Event>::none()),
+///         _ => (model.clone(), Effect::<Event>::none()),
+///     },
+///     |_model: &Model, _emitter: &Emitter<Event>| String::from("settings"),
+/// );
+/// let home = FnLogic::new(
+///     |visits: u32| (visits, Effect::<HomeEvent>::none()),
+///     |_event: HomeEvent, visits: &u32| (visits + 1, Effect::none()),
+///     |visits: &u32, _emitter: &Emitter<HomeEvent>| *visits,
+/// );
+///
+/// let logic = RouterLogic::new(|model: &Model| model.route, base).child(
+///     Route::Home,
+///     home,
+///     Lens::new(|model: &Model| &model.visits, |model, visits| model.visits = visits),
+///     |event| match event {
+///         Event::Home(event) => Ok(event),
+///         event => Err(event),
+///     },
+///     Event::Home,
+///     |visits: u32| format!("home, visited {visits} times"),
+/// );
+/// ```
+pub struct RouterLogic<Route, Event: Send, Model, Props, Base> {
+    route_of: Box<dyn Fn(&Model) -> Route>,
+    base: Base,
+    children: Vec<RoutedChild<Route, Event, Model, Props>>,
+}
+
+/// A child logic of a [`RouterLogic`] and the route it is active on.
+struct RoutedChild<Route, Event: Send, Model, Props> {
+    route: Route,
+    init: Box<ChildInit<Event, Model>>,
+    update: Box<ChildUpdate<Event, Model>>,
+    view: Box<ChildView<Event, Model, Props>>,
+    view_key: Box<ChildViewKey<Model>>,
+    view_deps: Box<ChildViewDeps<Model>>,
+}
+
+/// Init of a routed child, run on its part of the parent model.
+type ChildInit<Event, Model> = dyn Fn(Model) -> (Model, Effect<Event>);
+
+/// Update of a routed child, handing back events that aren't the child's.
+type ChildUpdate<Event, Model> = dyn Fn(Event, &Model) -> Result<(Model, Effect<Event>), Event>;
+
+/// View of a routed child, lifted to the parent's Props.
+type ChildView<Event, Model, Props> = dyn Fn(&Model, &Emitter<Event>) -> Props;
+
+/// View key of a routed child, distinct from those of other routes.
+type ChildViewKey<Model> = dyn Fn(&Model) -> Option<u64>;
+
+/// View dependencies of a routed child.
+type ChildViewDeps<Model> = dyn Fn(&Model) -> Vec<(&'static str, u64)>;
+
+impl<Route, Event, Model, Props, Base> RouterLogic<Route, Event, Model, Props, Base>
+where
+    Event: Send + 'static,
+    Model: Clone + 'static,
+    Props: 'static,
+{
+    /// Create a router reading the current route with `route_of` and falling
+    /// back to `base`.
+    pub fn new<R>(route_of: R, base: Base) -> Self
+    where
+        R: Fn(&Model) -> Route + 'static,
+    {
+        Self {
+            route_of: Box::new(route_of),
+            base,
+            children: Vec::new(),
+        }
+    }
+
+    /// Delegate to `child_logic` while the current route is `route`.
+    ///
+    /// # Arguments
+    ///
+    /// * `route` - The route on which the child is active
+    /// * `child_logic` - The child's logic
+    /// * `lens` - Selects the child's model within the parent model
+    /// * `unwrap` - Extracts the child's event, or hands back any other event
+    /// * `wrap` - Lifts the child's events into the parent's event type
+    /// * `into_props` - Converts the child's Props into the parent's
+    pub fn child<ChildEvent, ChildModel, ChildProps, Logic>(
+        mut self,
+        route: Route,
+        child_logic: Logic,
+        lens: Lens<Model, ChildModel>,
+        unwrap: fn(Event) -> Result<ChildEvent, Event>,
+        wrap: fn(ChildEvent) -> Event,
+        into_props: fn(ChildProps) -> Props,
+    ) -> Self
+    where
+        ChildEvent: Send + 'static,
+        ChildModel: Clone + 'static,
+        ChildProps: 'static,
+        Logic: MvuLogic<ChildEvent, ChildModel, ChildProps> + 'static,
+    {
+        let logic = Arc::new(child_logic);
+        let init = {
+            let logic = logic.clone();
+            move |mut model: Model| {
+                let (child, effect) = logic.init(lens.get(&model).clone());
+                lens.set(&mut model, child);
+                (model, effect.map(wrap))
+            }
+        };
+        let update = {
+            let logic = logic.clone();
+            move |event, model: &Model| {
+                let (child, effect) = logic.update(unwrap(event)?, lens.get(model));
+                let mut model = model.clone();
+                lens.set(&mut model, child);
+                Ok((model, effect.map(wrap)))
+            }
+        };
+        let view = {
+            let logic = logic.clone();
+            move |model: &Model, emitter: &Emitter<Event>| {
+                into_props(logic.view(lens.get(model), &emitter.contramap(wrap)))
+            }
+        };
+        // Mix in the child's position, so that two routes never share a key
+        let salt = (self.children.len() as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        let view_key = {
+            let logic = logic.clone();
+            move |model: &Model| logic.view_key(lens.get(model)).map(|key| key ^ salt)
+        };
+        let view_deps = move |model: &Model| logic.view_deps(lens.get(model));
+
+        self.children.push(RoutedChild {
+            route,
+            init: Box::new(init),
+            update: Box::new(update),
+            view: Box::new(view),
+            view_key: Box::new(view_key),
+            view_deps: Box::new(view_deps),
+        });
+        self
+    }
+}
+
+impl<Route, Event, Model, Props, Base> RouterLogic<Route, Event, Model, Props, Base>
+where
+    Route: PartialEq,
+    Event: Send,
+{
+    /// The child registered for the model's current route, if any.
+    fn active(&self, model: &Model) -> Option<&RoutedChild<Route, Event, Model, Props>> {
+        let route = (self.route_of)(model);
+        self.children.iter().find(|child| child.route == route)
+    }
+}
+
+impl<Route, Event, Model, Props, Base> MvuLogic<Event, Model, Props>
+    for RouterLogic<Route, Event, Model, Props, Base>
+where
+    Route: PartialEq,
+    Event: Send + 'static,
+    Base: MvuLogic<Event, Model, Props>,
+{
+    fn init(&self, model: Model) -> (Model, Effect<Event>) {
+        let (model, effect) = self.base.init(model);
+        match self.active(&model) {
+            Some(child) => {
+                let (model, child_effect) = (child.init)(model);
+                (model, effect + child_effect)
+            }
+            None => (model, effect),
+        }
+    }
+
+    fn update(&self, event: Event, model: &Model) -> (Model, Effect<Event>) {
+        let event = match self.active(model) {
+            Some(child) => match (child.update)(event, model) {
+                Ok(updated) => return updated,
+                Err(event) => event,
+            },
+            None => event,
+        };
+
+        self.base.update(event, model)
+    }
+
+    fn view(&self, model: &Model, emitter: &Emitter<Event>) -> Props {
+        match self.active(model) {
+            Some(child) => (child.view)(model, emitter),
+            None => self.base.view(model, emitter),
+        }
+    }
+
+    fn view_key(&self, model: &Model) -> Option<u64> {
+        match self.active(model) {
+            Some(child) => (child.view_key)(model),
+            None => self.base.view_key(model),
+        }
+    }
+
+    fn view_deps(&self, model: &Model) -> Vec<(&'static str, u64)> {
+        match self.active(model) {
+            Some(child) => (child.view_deps)(model),
+            None => self.base.view_deps(model),
+        }
+    }
+}
+
+/// Focus on one part of a model, such as a feature's sub-model.
+///
+/// A lens pairs a getter borrowing the part with a setter replacing it. Used
+/// with [`DispatchLogic::route_lens`] to write a feature's update against its
+/// own sub-model.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::Lens;
+///
+/// struct Model { count: i32 }
+///
+/// let count = Lens::new(|model: &Model| &model.count, |model, count| model.count = count);
+///
+/// let mut model = Model { count: 1 };
+/// let incremented = count.get(&model) + 1;
+/// count.set(&mut model, incremented);
+/// assert_eq!(model.count, 2);
+/// ```
+pub struct Lens<Model, Part> {
+    get: fn(&Model) -> &Part,
+    set: fn(&mut Model, Part),
+}
+
+impl<Model, Part> Clone for Lens<Model, Part> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Model, Part> Copy for Lens<Model, Part> {}
+
+impl<Model, Part> Lens<Model, Part> {
+    /// Create a lens from its getter and setter.
+    pub fn new(get: fn(&Model) -> &Part, set: fn(&mut Model, Part)) -> Self {
+        Self { get, set }
+    }
+
+    /// Borrow the part of `model` this lens focuses on.
+    pub fn get<'m>(&self, model: &'m Model) -> &'m Part {
+        (self.get)(model)
+    }
+
+    /// Replace the part of `model` this lens focuses on.
+    pub fn set(&self, model: &mut Model, part: Part) {
+        (self.set)(model, part)
+    }
+}
+
+/// Helper for a parent [`MvuLogic::view`] composed of child views.
+///
+/// Each [`child`](Self::child) call runs a child logic's `view` against the
+/// part of the parent model selected by a lens, with an emitter that lifts the
+/// child's events into the parent's event type. The resulting child Props can
+/// then be embedded in the parent's Props.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{Effect, Emitter, FnLogic, MvuLogic, ViewComposer};
+///
+/// enum CounterEvent { Increment }
+///
+/// struct Dashboard { visitors: i32, orders: i32 }
+///
+/// enum Event { Visitors(CounterEvent), Orders(CounterEvent) }
+///
+/// struct DashboardProps { visitors: i32, orders: i32 }
+///
+/// let counter = FnLogic::new(
+///     |count: i32| (count, Effect::<CounterEvent>::none()),
+///     |_event: CounterEvent, count: &i32| (count + 1, Effect::<CounterEvent>::none()),
+///     |count: &i32, _emitter: &Emitter<CounterEvent>| *count,
+/// );
+///
+/// fn view(counter: &impl MvuLogic<CounterEvent, i32, i32>, model: &Dashboard, emitter: &Emitter<Event>) -> DashboardProps {
+///     let composer = ViewComposer::new(model, emitter);
+///     DashboardProps {
+///         visitors: composer.child(|model| &model.visitors, counter, Event::Visitors),
+///         orders: composer.child(|model| &model.orders, counter, Event::Orders),
+///     }
+/// }
+/// ```
+pub struct ViewComposer<'a, Event: Send, Model> {
+    model: &'a Model,
+    emitter: &'a Emitter<Event>,
+}
+
+impl<'a, Event: Send + 'static, Model> ViewComposer<'a, Event, Model> {
+    /// Create a composer for the parent's `model` and `emitter`.
+    pub fn new(model: &'a Model, emitter: &'a Emitter<Event>) -> Self {
+        Self { model, emitter }
+    }
+
+    /// Compute a child's Props.
+    ///
+    /// # Arguments
+    ///
+    /// * `lens` - Selects the child's model within the parent model
+    /// * `child_logic` - The child's logic, whose `view` is called
+    /// * `tag` - Lifts the child's events into the parent's event type
+    pub fn child<ChildEvent, ChildModel, ChildProps, Lens, Logic, Tag>(
+        &self,
+        lens: Lens,
+        child_logic: &Logic,
+        tag: Tag,
+    ) -> ChildProps
+    where
+        ChildEvent: Send,
+        Lens: FnOnce(&Model) -> &ChildModel,
+        Logic: MvuLogic<ChildEvent, ChildModel, ChildProps>,
+        Tag: Fn(ChildEvent) -> Event + Send + Sync + 'static,
+    {
+        child_logic.view(lens(self.model), &self.emitter.contramap(tag))
+    }
+}
+
+/// Event addressed to one of several same-typed children, keyed by `id`.
+///
+/// Covers the "list of identical children" case, such as the items of a todo
+/// list, without a hand-written wrapper event and dispatch per component. The
+/// parent keeps its children as `(id, model)` pairs and wraps their events in
+/// a single variant of its own event type, e.g. `Event::Item(Routed<u32, ItemEvent>)`.
+/// [`views`](Self::views) renders every child with an emitter that tags its
+/// events with the child's id, and [`update`](Self::update) dispatches a tagged
+/// event back to that child.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{Effect, Emitter, FnLogic, MvuLogic, Routed};
+///
+/// enum ItemEvent { Toggle }
+///
+/// enum Event { Item(Routed<u32, ItemEvent>) }
+///
+/// #[derive(Clone)]
+/// struct Model { items: Vec<(u32, bool)> }
+///
+/// fn update(item: &impl MvuLogic<ItemEvent, bool, bool>, event: Event, model: &Model) -> (Model, Effect<Event>) {
+///     let Event::Item(routed) = event;
+///     let mut model = model.clone();
+///     let effect = routed.update(item, &mut model.items, Event::Item);
+///     (model, effect)
+/// }
+///
+/// fn view(item: &impl MvuLogic<ItemEvent, bool, bool>, model: &Model, emitter: &Emitter<Event>) -> Vec<bool> {
+///     Routed::views(item, &model.items, emitter, Event::Item)
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Routed<Id, Inner> {
+    /// Id of the child the event is addressed to.
+    pub id: Id,
+    /// The child's own event.
+    pub inner: Inner,
+}
+
+impl<Id, Inner> Routed<Id, Inner>
+where
+    Id: PartialEq + Clone + Send + Sync + 'static,
+    Inner: Send + 'static,
+{
+    /// Address `inner` to the child with `id`.
+    pub fn new(id: Id, inner: Inner) -> Self {
+        Self { id, inner }
+    }
+
+    /// Update the child whose id matches with `child_logic`, in place.
+    ///
+    /// Returns the child's effect, with its events addressed back to the same
+    /// child and lifted into the parent's event type with `wrap`. Events for an
+    /// id that isn't in `children`, e.g. one removed in the meantime, are
+    /// ignored.
+    pub fn update<Event, ChildModel, ChildProps, Logic, Wrap>(
+        self,
+        child_logic: &Logic,
+        children: &mut [(Id, ChildModel)],
+        wrap: Wrap,
+    ) -> Effect<Event>
+    where
+        Event: Send + 'static,
+        Logic: MvuLogic<Inner, ChildModel, ChildProps>,
+        Wrap: Fn(Routed<Id, Inner>) -> Event + Send + Sync + 'static,
+    {
+        let Some((_, child)) = children.iter_mut().find(|(id, _)| *id == self.id) else {
+            return Effect::none();
+        };
+
+        let (updated, effect) = child_logic.update(self.inner, child);
+        *child = updated;
+
+        let id = self.id;
+        effect.map(move |inner| wrap(Routed::new(id.clone(), inner)))
+    }
+
+    /// Compute the Props of every child, in order.
+    ///
+    /// Each child's view gets an emitter that addresses its events to the
+    /// child's id and lifts them into the parent's event type with `wrap`.
+    pub fn views<Event, ChildModel, ChildProps, Logic, Wrap>(
+        child_logic: &Logic,
+        children: &[(Id, ChildModel)],
+        emitter: &Emitter<Event>,
+        wrap: Wrap,
+    ) -> Vec<ChildProps>
+    where
+        Event: Send + 'static,
+        Logic: MvuLogic<Inner, ChildModel, ChildProps>,
+        Wrap: Fn(Routed<Id, Inner>) -> Event + Clone + Send + Sync + 'static,
+    {
+        children
+            .iter()
+            .map(|(id, child)| {
+                let id = id.clone();
+                let wrap = wrap.clone();
+                let emitter = emitter.contramap(move |inner| wrap(Routed::new(id.clone(), inner)));
+                child_logic.view(child, &emitter)
+            })
+            .collect()
+    }
 }
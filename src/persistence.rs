@@ -0,0 +1,26 @@
+//! Storage abstraction for saving the model.
+
+/// Storage the model can be saved to.
+///
+/// Implement this for your storage backend (a file, local storage, flash,
+/// etc.) and save the current model with [`Effect::persist`](crate::Effect::persist).
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::Persistence;
+///
+/// struct Settings { volume: u8 }
+///
+/// struct ConsolePersistence;
+///
+/// impl Persistence<Settings> for ConsolePersistence {
+///     fn save(&self, model: &Settings) {
+///         println!("saving volume {}", model.volume);
+///     }
+/// }
+/// ```
+pub trait Persistence<Model> {
+    /// Save `model`, replacing any previously saved model.
+    fn save(&self, model: &Model);
+}
@@ -0,0 +1,70 @@
+//! One-shot rendering of a program's initial state, e.g. for server-side rendering.
+
+#[cfg(feature = "no_std")]
+use alloc::string::String;
+
+use core::task::Context;
+
+use crate::runtime::noop_waker;
+use crate::{Emitter, MvuLogic};
+
+/// Render the initial state of a program to a string without running an event loop.
+///
+/// Runs [`MvuLogic::init`] on `init_model`, applies the events its effect emits
+/// synchronously, reduces the resulting model with [`MvuLogic::view`] and
+/// passes the Props to `serialize`. This is intended for server-side
+/// prerendering, where the page is produced once and no runtime is kept alive.
+///
+/// The init effect is polled exactly once. Events it emits immediately, such as
+/// those from [`Effect::just`](crate::Effect::just) or [`Effect::batch`](crate::Effect::batch),
+/// are passed to [`MvuLogic::update`]; any asynchronous work that has not
+/// completed by then is dropped. Effects returned by `update` are not executed,
+/// and events emitted by callbacks in the Props are discarded.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{prerender, Effect, Emitter, MvuLogic};
+///
+/// enum Event { Loaded(u32) }
+///
+/// struct Counter;
+///
+/// impl MvuLogic<Event, u32, u32> for Counter {
+///     fn init(&self, model: u32) -> (u32, Effect<Event>) { (model, Effect::just(Event::Loaded(3))) }
+///     fn update(&self, Event::Loaded(n): Event, _: &u32) -> (u32, Effect<Event>) { (n, Effect::none()) }
+///     fn view(&self, model: &u32, _: &Emitter<Event>) -> u32 { *model }
+/// }
+///
+/// let html = prerender(&Counter, 0, |count| format!("<p>{}</p>", count));
+/// assert_eq!(html, "<p>3</p>");
+/// ```
+pub fn prerender<Event, Model, Props, Logic, Serialize>(
+    logic: &Logic,
+    init_model: Model,
+    serialize: Serialize,
+) -> String
+where
+    Event: Send + 'static,
+    Logic: MvuLogic<Event, Model, Props>,
+    Serialize: FnOnce(&Props) -> String,
+{
+    let (event_sender, event_receiver) = flume::unbounded();
+    let emitter = Emitter::new(event_sender);
+
+    let (mut model, init_effect) = logic.init(init_model);
+
+    // Only the synchronous part of the init effect can contribute to the output
+    let _ = init_effect
+        .execute(&emitter)
+        .as_mut()
+        .poll(&mut Context::from_waker(&noop_waker()));
+
+    for event in event_receiver.drain() {
+        let (new_model, _) = logic.update(event, &model);
+        model = new_model;
+    }
+
+    let props = logic.view(&model, &emitter);
+    serialize(&props)
+}
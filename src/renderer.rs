@@ -1,14 +1,21 @@
 //! Renderer abstraction for rendering Props.
 
-#[cfg(any(test, feature = "testing"))]
+#[cfg(all(feature = "no_std", any(test, feature = "testing")))]
+use alloc::boxed::Box;
+#[cfg(feature = "no_std")]
+use alloc::collections::VecDeque;
 #[cfg(feature = "no_std")]
 use alloc::vec::Vec;
+#[cfg(not(feature = "no_std"))]
+use std::collections::VecDeque;
+
+use core::time::Duration;
 
-#[cfg(any(test, feature = "testing"))]
 use portable_atomic_util::Arc;
-#[cfg(any(test, feature = "testing"))]
 use spin::Mutex;
 
+use crate::Clock;
+
 /// Renderer abstraction for rendering Props.
 ///
 /// Implement this trait to integrate oxide-mvu just your rendering system
@@ -46,6 +53,310 @@ pub trait Renderer<Props> {
     fn render(&mut self, props: Props);
 }
 
+/// Renderer that switches to a fallback when the primary renderer panics.
+///
+/// Only available without the `no_std` feature.
+///
+/// Each render is first attempted on the primary renderer. If it panics, the
+/// panic is caught and the same Props are rendered by the fallback instead.
+/// All subsequent renders go directly to the fallback, so a broken primary
+/// renderer can't take down the event loop.
+///
+/// The panic message is reported by the standard panic hook as usual. To log
+/// the switch to the fallback, register a callback with
+/// [`on_fallback`](Self::on_fallback).
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{FallbackRenderer, Renderer};
+///
+/// #[derive(Clone)]
+/// struct Props { message: &'static str }
+///
+/// struct GraphicalRenderer;
+/// impl Renderer<Props> for GraphicalRenderer {
+///     fn render(&mut self, props: Props) { /* draw to screen */ }
+/// }
+///
+/// struct ConsoleRenderer;
+/// impl Renderer<Props> for ConsoleRenderer {
+///     fn render(&mut self, props: Props) { println!("{}", props.message); }
+/// }
+///
+/// let renderer = FallbackRenderer::new(GraphicalRenderer, ConsoleRenderer)
+///     .on_fallback(|message| eprintln!("primary renderer panicked: {message}"));
+/// ```
+#[cfg(not(feature = "no_std"))]
+pub struct FallbackRenderer<Primary, Fallback> {
+    primary: Primary,
+    fallback: Fallback,
+    primary_failed: bool,
+    on_fallback: Option<Box<FallbackHook>>,
+}
+
+/// Callback configured via [`FallbackRenderer::on_fallback`].
+#[cfg(not(feature = "no_std"))]
+type FallbackHook = dyn FnMut(&str) + Send;
+
+#[cfg(not(feature = "no_std"))]
+impl<Primary, Fallback> FallbackRenderer<Primary, Fallback> {
+    /// Create a renderer that uses `primary` until it panics, then `fallback`.
+    pub fn new(primary: Primary, fallback: Fallback) -> Self {
+        Self {
+            primary,
+            fallback,
+            primary_failed: false,
+            on_fallback: None,
+        }
+    }
+
+    /// Call `on_fallback` with the panic message when the primary renderer
+    /// panics and the fallback takes over.
+    ///
+    /// This is the place to log the switch, which otherwise happens silently
+    /// apart from the standard panic hook's report. Panics whose payload isn't
+    /// a string are reported as `"unknown panic"`.
+    pub fn on_fallback<F>(mut self, on_fallback: F) -> Self
+    where
+        F: FnMut(&str) + Send + 'static,
+    {
+        self.on_fallback = Some(Box::new(on_fallback));
+        self
+    }
+
+    /// Whether the primary renderer has panicked and the fallback is in use.
+    pub fn is_using_fallback(&self) -> bool {
+        self.primary_failed
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<Props, Primary, Fallback> Renderer<Props> for FallbackRenderer<Primary, Fallback>
+where
+    Props: Clone,
+    Primary: Renderer<Props>,
+    Fallback: Renderer<Props>,
+{
+    fn render(&mut self, props: Props) {
+        if !self.primary_failed {
+            let primary = &mut self.primary;
+            let attempt = props.clone();
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+                primary.render(attempt);
+            }));
+
+            let Err(panic) = result else {
+                return;
+            };
+
+            self.primary_failed = true;
+            if let Some(on_fallback) = &mut self.on_fallback {
+                let message = match (panic.downcast_ref::<&str>(), panic.downcast_ref::<String>()) {
+                    (Some(message), _) => message,
+                    (_, Some(message)) => message.as_str(),
+                    _ => "unknown panic",
+                };
+                on_fallback(message);
+            }
+        }
+
+        self.fallback.render(props);
+    }
+}
+
+/// Renderer that keeps the most recent Props for diagnostics.
+///
+/// Every render is forwarded to the inner renderer, and a clone of the Props is
+/// kept in a fixed-size ring holding the last `capacity` renders. Unlike
+/// [`TestRenderer`](crate::TestRenderer) the memory used is bounded, so it is
+/// suitable for production, e.g. to dump recent render history after a crash.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{Renderer, RingBufferRenderer};
+///
+/// struct NoopRenderer;
+/// impl Renderer<i32> for NoopRenderer {
+///     fn render(&mut self, _props: i32) {}
+/// }
+///
+/// let mut renderer = RingBufferRenderer::new(NoopRenderer, 2);
+/// renderer.render(1);
+/// renderer.render(2);
+/// renderer.render(3);
+///
+/// assert_eq!(renderer.recent(), vec![2, 3]);
+/// ```
+pub struct RingBufferRenderer<Props, Inner> {
+    inner: Inner,
+    capacity: usize,
+    recent: VecDeque<Props>,
+}
+
+impl<Props, Inner> RingBufferRenderer<Props, Inner> {
+    /// Create a renderer forwarding to `inner` and retaining the last `capacity` Props.
+    pub fn new(inner: Inner, capacity: usize) -> Self {
+        Self {
+            inner,
+            capacity,
+            recent: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// The retained Props, oldest first.
+    pub fn recent(&self) -> Vec<Props>
+    where
+        Props: Clone,
+    {
+        self.recent.iter().cloned().collect()
+    }
+}
+
+impl<Props, Inner> Renderer<Props> for RingBufferRenderer<Props, Inner>
+where
+    Props: Clone,
+    Inner: Renderer<Props>,
+{
+    fn render(&mut self, props: Props) {
+        if self.capacity > 0 {
+            if self.recent.len() == self.capacity {
+                self.recent.pop_front();
+            }
+            self.recent.push_back(props.clone());
+        }
+
+        self.inner.render(props);
+    }
+}
+
+/// Renderer that forwards to an expensive inner renderer at most once per interval.
+///
+/// Props rendered within `interval` of the last forwarded render are held back,
+/// and only the most recent of them is kept. The held Props are forwarded by the
+/// next render once the interval has passed, or by
+/// [`flush_trailing`](Self::flush_trailing), which the host should call
+/// periodically (e.g. on each frame) so the final Props of a burst are drawn
+/// once things quiet down.
+///
+/// Clones share the same inner renderer, so the host can keep a clone to call
+/// `flush_trailing` while the runtime renders through another.
+///
+/// # Example
+///
+/// ```rust
+/// use core::time::Duration;
+/// use oxide_mvu::{Clock, Renderer, ThrottledRenderer};
+///
+/// struct PlatformClock;
+/// impl Clock for PlatformClock {
+///     fn now(&self) -> Duration { /* read the platform timer */ Duration::ZERO }
+/// }
+///
+/// struct ChartRenderer;
+/// impl Renderer<Vec<f64>> for ChartRenderer {
+///     fn render(&mut self, _points: Vec<f64>) { /* expensive redraw */ }
+/// }
+///
+/// let renderer = ThrottledRenderer::new(ChartRenderer, PlatformClock, Duration::from_millis(100));
+/// let trailing = renderer.clone();
+/// // Call `trailing.flush_trailing()` from the host's frame callback.
+/// ```
+pub struct ThrottledRenderer<Props, Inner, C> {
+    throttle: Arc<Mutex<Throttle<Props, Inner, C>>>,
+}
+
+/// State shared by clones of a [`ThrottledRenderer`].
+struct Throttle<Props, Inner, C> {
+    inner: Inner,
+    clock: C,
+    interval: Duration,
+    last_forwarded: Option<Duration>,
+    pending: Option<Props>,
+}
+
+impl<Props, Inner, C> Clone for ThrottledRenderer<Props, Inner, C> {
+    fn clone(&self) -> Self {
+        Self {
+            throttle: self.throttle.clone(),
+        }
+    }
+}
+
+impl<Props, Inner, C> ThrottledRenderer<Props, Inner, C>
+where
+    Inner: Renderer<Props>,
+    C: Clock,
+{
+    /// Create a renderer forwarding to `inner` at most once per `interval` of `clock`.
+    pub fn new(inner: Inner, clock: C, interval: Duration) -> Self {
+        Self {
+            throttle: Arc::new(Mutex::new(Throttle {
+                inner,
+                clock,
+                interval,
+                last_forwarded: None,
+                pending: None,
+            })),
+        }
+    }
+
+    /// Forward the held-back Props if the interval has passed since the last
+    /// forwarded render.
+    ///
+    /// Returns whether Props were forwarded.
+    pub fn flush_trailing(&self) -> bool {
+        let mut throttle = self.throttle.lock();
+        if !throttle.is_open() {
+            return false;
+        }
+
+        match throttle.pending.take() {
+            Some(props) => {
+                throttle.forward(props);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<Props, Inner, C> Throttle<Props, Inner, C>
+where
+    Inner: Renderer<Props>,
+    C: Clock,
+{
+    /// Whether enough time has passed to forward another render.
+    fn is_open(&self) -> bool {
+        match self.last_forwarded {
+            None => true,
+            Some(last) => self.clock.now().saturating_sub(last) >= self.interval,
+        }
+    }
+
+    fn forward(&mut self, props: Props) {
+        self.last_forwarded = Some(self.clock.now());
+        self.inner.render(props);
+    }
+}
+
+impl<Props, Inner, C> Renderer<Props> for ThrottledRenderer<Props, Inner, C>
+where
+    Inner: Renderer<Props>,
+    C: Clock,
+{
+    fn render(&mut self, props: Props) {
+        let mut throttle = self.throttle.lock();
+        if throttle.is_open() {
+            throttle.pending = None;
+            throttle.forward(props);
+        } else {
+            throttle.pending = Some(props);
+        }
+    }
+}
+
 #[cfg(any(test, feature = "testing"))]
 /// Test renderer that captures all rendered Props for assertions.
 ///
@@ -169,3 +480,101 @@ impl<Props: 'static> TestRenderer<Props> {
         f(&renders)
     }
 }
+
+#[cfg(any(test, feature = "testing"))]
+/// Test renderer that records what changed between consecutive renders.
+///
+/// Only available with the `testing` feature.
+///
+/// Each render after the first is compared with the previous one using the
+/// provided diff function, and the resulting changes are recorded. The first
+/// render has nothing to compare against and records no changes. The change
+/// type is up to the test, typically an enum with a variant per Props field.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{DiffTestRenderer, Renderer};
+///
+/// struct Props { count: i32, label: &'static str }
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum FieldChange { Count(i32, i32), Label }
+///
+/// let mut renderer = DiffTestRenderer::new(|previous: &Props, next: &Props| {
+///     let mut changes = Vec::new();
+///     if previous.count != next.count {
+///         changes.push(FieldChange::Count(previous.count, next.count));
+///     }
+///     if previous.label != next.label {
+///         changes.push(FieldChange::Label);
+///     }
+///     changes
+/// });
+///
+/// renderer.render(Props { count: 0, label: "count" });
+/// renderer.render(Props { count: 1, label: "count" });
+///
+/// assert_eq!(renderer.changes(), vec![vec![FieldChange::Count(0, 1)]]);
+/// ```
+pub struct DiffTestRenderer<Props, Change> {
+    recording: Arc<Mutex<DiffRecording<Props, Change>>>,
+}
+
+#[cfg(any(test, feature = "testing"))]
+/// State shared by clones of a [`DiffTestRenderer`].
+struct DiffRecording<Props, Change> {
+    diff: Box<DiffFn<Props, Change>>,
+    previous: Option<Props>,
+    changes: Vec<Vec<Change>>,
+}
+
+#[cfg(any(test, feature = "testing"))]
+/// Computes the changes between two consecutive Props.
+type DiffFn<Props, Change> = dyn Fn(&Props, &Props) -> Vec<Change> + Send;
+
+#[cfg(any(test, feature = "testing"))]
+impl<Props, Change> Clone for DiffTestRenderer<Props, Change> {
+    fn clone(&self) -> Self {
+        Self {
+            recording: self.recording.clone(),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl<Props, Change> DiffTestRenderer<Props, Change> {
+    /// Create a renderer recording the changes computed by `diff`.
+    pub fn new<F>(diff: F) -> Self
+    where
+        F: Fn(&Props, &Props) -> Vec<Change> + Send + 'static,
+    {
+        Self {
+            recording: Arc::new(Mutex::new(DiffRecording {
+                diff: Box::new(diff),
+                previous: None,
+                changes: Vec::new(),
+            })),
+        }
+    }
+
+    /// The changes recorded for each render after the first, in render order.
+    pub fn changes(&self) -> Vec<Vec<Change>>
+    where
+        Change: Clone,
+    {
+        self.recording.lock().changes.clone()
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl<Props, Change> Renderer<Props> for DiffTestRenderer<Props, Change> {
+    fn render(&mut self, props: Props) {
+        let mut recording = self.recording.lock();
+        if let Some(previous) = &recording.previous {
+            let changes = (recording.diff)(previous, &props);
+            recording.changes.push(changes);
+        }
+        recording.previous = Some(props);
+    }
+}
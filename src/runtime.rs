@@ -2,13 +2,30 @@
 
 #[cfg(feature = "no_std")]
 use alloc::boxed::Box;
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
 
 use core::future::Future;
 use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
 
-use flume::Receiver;
+use flume::r#async::RecvFut;
+use flume::{Receiver, Sender};
+#[cfg(feature = "no_std")]
+use portable_atomic::AtomicBool;
+use portable_atomic::{AtomicUsize, Ordering};
+use portable_atomic_util::Arc;
+use spin::Mutex;
 
-use crate::{Emitter, MvuLogic, Renderer};
+#[cfg(any(test, feature = "testing"))]
+use crate::effect::ExecutionLog;
+use crate::handle::{ChannelCounters, Command, EventHook, ModelObserver};
+#[cfg(not(feature = "no_std"))]
+use crate::handle::{EffectInFlight, IdleSignal};
+use crate::{BatchMvuLogic, Clock, Effect, Emitter, MvuLogic, Renderer, RuntimeHandle};
+#[cfg(any(test, feature = "testing"))]
+use crate::{EffectDescription, TestRenderer};
 
 /// A spawner trait for executing futures on an async runtime.
 ///
@@ -18,6 +35,13 @@ use crate::{Emitter, MvuLogic, Renderer};
 pub trait Spawner {
     /// Spawn a future on the async runtime.
     fn spawn(&self, future: Pin<Box<dyn Future<Output = ()> + Send>>);
+
+    /// Make progress on spawned futures that the runtime is responsible for driving.
+    ///
+    /// Called by the runtime's event loop each time it processes events. Spawners
+    /// backed by an external executor don't need to do anything here, which is the
+    /// default. See [`CooperativeSpawner`] for a spawner that relies on it.
+    fn poll_spawned(&self, _cx: &mut Context<'_>) {}
 }
 
 /// Implement Spawner for any callable type that matches the signature.
@@ -32,6 +56,245 @@ where
     }
 }
 
+/// Spawned effect future awaiting completion.
+type Task = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Built-in executor that runs effects on the runtime's own event loop.
+///
+/// Spawned futures are kept in a task list owned by the spawner and polled by
+/// [`MvuRuntime::run`] (or [`MvuRuntime::poll_once`]) between processing events,
+/// so async effects work without an external async runtime. Works with `no_std`.
+///
+/// Tasks are polled cooperatively on the event loop's thread: a future that
+/// blocks (e.g. on synchronous I/O) blocks event processing with it.
+///
+/// # Example
+///
+/// ```rust
+/// # use oxide_mvu::{Emitter, Effect, MvuLogic, MvuRuntime, Renderer};
+/// # #[derive(Clone)] enum Event {}
+/// # #[derive(Clone)] struct Model;
+/// # struct Logic;
+/// # impl MvuLogic<Event, Model, ()> for Logic {
+/// #     fn init(&self, m: Model) -> (Model, Effect<Event>) { (m, Effect::none()) }
+/// #     fn update(&self, _: Event, m: &Model) -> (Model, Effect<Event>) { (m.clone(), Effect::none()) }
+/// #     fn view(&self, _: &Model, _: &Emitter<Event>) {}
+/// # }
+/// # struct NoopRenderer;
+/// # impl Renderer<()> for NoopRenderer { fn render(&mut self, _: ()) {} }
+/// use oxide_mvu::CooperativeSpawner;
+///
+/// let runtime = MvuRuntime::new(Model, Logic, NoopRenderer, CooperativeSpawner::new());
+/// ```
+#[derive(Clone, Default)]
+pub struct CooperativeSpawner {
+    tasks: Arc<Mutex<Vec<Task>>>,
+}
+
+impl CooperativeSpawner {
+    /// Create a spawner with an empty task list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of spawned futures that have not completed yet.
+    pub fn pending_tasks(&self) -> usize {
+        self.tasks.lock().len()
+    }
+}
+
+impl Spawner for CooperativeSpawner {
+    fn spawn(&self, future: Pin<Box<dyn Future<Output = ()> + Send>>) {
+        self.tasks.lock().push(future);
+    }
+
+    fn poll_spawned(&self, cx: &mut Context<'_>) {
+        // Poll without holding the lock, so tasks completing can't deadlock on it
+        let mut tasks = core::mem::take(&mut *self.tasks.lock());
+        tasks.retain_mut(|task| task.as_mut().poll(cx).is_pending());
+
+        self.tasks.lock().append(&mut tasks);
+    }
+}
+
+/// What the runtime does when processing an event panics.
+///
+/// Only available without the `no_std` feature. Configure it with
+/// [`MvuRuntime::with_panic_policy`]. The policy covers everything done to
+/// process a batch of events: [`MvuLogic::update`], [`MvuLogic::view`] and
+/// rendering. Panics inside spawned effects are left to the host's executor.
+///
+/// The panic message is reported by the standard panic hook as usual.
+#[cfg(not(feature = "no_std"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PanicPolicy {
+    /// Abort the process.
+    Abort,
+    /// Discard the events being processed and carry on with the next ones.
+    ///
+    /// The model is left as it was before the panicking event.
+    SkipEvent,
+    /// Stop the event loop. [`MvuRuntime::run`] completes and no further
+    /// events are processed.
+    Shutdown,
+}
+
+/// Failure caught by an [`ErrorBoundary`] while processing events.
+///
+/// Only available without the `no_std` feature. Each variant names the part of
+/// the cycle that panicked and carries the panic message, or `"unknown panic"`
+/// if the payload isn't a string.
+#[cfg(not(feature = "no_std"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RuntimeError {
+    /// Processing an event panicked before rendering, usually in
+    /// [`MvuLogic::update`].
+    Update(String),
+    /// [`MvuLogic::view`] or a [`PropsTransform`] panicked.
+    View(String),
+    /// The renderer, or a view registered with [`MvuRuntime::add_view`], panicked.
+    Render(String),
+}
+
+/// What the runtime does with a failure handled by an [`ErrorBoundary`].
+///
+/// Whatever the action, the events being processed are discarded and the model
+/// is left as it was before them.
+#[cfg(not(feature = "no_std"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErrorAction<Event, Model> {
+    /// Queue an event, such as one that records the failure in the model.
+    Emit(Event),
+    /// Replace the model and render it.
+    SetModel(Model),
+    /// Carry on with the next events, like [`PanicPolicy::SkipEvent`].
+    Ignore,
+    /// Stop the event loop, like [`PanicPolicy::Shutdown`].
+    Shutdown,
+}
+
+/// Turns failures while processing events into events or model changes.
+///
+/// Only available without the `no_std` feature. Configure it with
+/// [`MvuRuntime::with_error_boundary`]. Like a [`PanicPolicy`], the boundary
+/// covers [`MvuLogic::update`], [`MvuLogic::view`] and rendering, but lets the
+/// app decide per failure, for example by showing an error screen instead of
+/// shutting down. Panics inside spawned effects are left to the host's executor.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{ErrorAction, ErrorBoundary, RuntimeError};
+///
+/// #[derive(Clone)]
+/// struct Model { error: Option<String> }
+///
+/// enum Event { Retry }
+///
+/// struct ShowErrors;
+///
+/// impl ErrorBoundary<Event, Model> for ShowErrors {
+///     fn handle(&self, error: RuntimeError, model: &Model) -> ErrorAction<Event, Model> {
+///         match error {
+///             RuntimeError::Update(_) => ErrorAction::Emit(Event::Retry),
+///             RuntimeError::View(message) | RuntimeError::Render(message) => {
+///                 ErrorAction::SetModel(Model { error: Some(message), ..model.clone() })
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[cfg(not(feature = "no_std"))]
+pub trait ErrorBoundary<Event, Model> {
+    /// Decide what to do about `error`, given the model from before the
+    /// failed events.
+    fn handle(&self, error: RuntimeError, model: &Model) -> ErrorAction<Event, Model>;
+}
+
+/// Part of the cycle being run, reported to an [`ErrorBoundary`] on failure.
+#[cfg(not(feature = "no_std"))]
+#[derive(Clone, Copy)]
+enum CycleStage {
+    Update,
+    View,
+    Render,
+}
+
+/// When the initial model is rendered relative to the initial effect.
+///
+/// Configure it with [`MvuRuntime::with_init_render_mode`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InitRenderMode {
+    /// Render the model returned by [`MvuLogic::init`] before spawning the
+    /// initial effect.
+    #[default]
+    BeforeEffects,
+    /// Spawn the initial effect and process the events it emits right away,
+    /// then render the settled model. The pre-effect model is never rendered.
+    AfterEffects,
+}
+
+/// Pre-processing step applied to each batch of queued events.
+///
+/// When registered with [`MvuRuntime::with_coalescer`], the runtime drains all
+/// currently queued events into a batch and passes it to [`coalesce`](Self::coalesce)
+/// before processing. Implementations may merge, reorder or remove events, for
+/// example folding consecutive "scroll by" events into a single one.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::Coalescer;
+///
+/// enum Event { ScrollBy(i32), Click }
+///
+/// struct SumScrolls;
+///
+/// impl Coalescer<Event> for SumScrolls {
+///     fn coalesce(&self, pending: &mut Vec<Event>) {
+///         let mut coalesced: Vec<Event> = Vec::with_capacity(pending.len());
+///         for event in pending.drain(..) {
+///             match (coalesced.last_mut(), event) {
+///                 (Some(Event::ScrollBy(total)), Event::ScrollBy(delta)) => *total += delta,
+///                 (_, event) => coalesced.push(event),
+///             }
+///         }
+///         *pending = coalesced;
+///     }
+/// }
+/// ```
+pub trait Coalescer<Event> {
+    /// Merge or otherwise rewrite a batch of pending events before processing.
+    fn coalesce(&self, pending: &mut Vec<Event>);
+}
+
+/// Post-processing step applied to Props before they are rendered.
+///
+/// Registered with [`MvuRuntime::with_props_transform`], transforms run after
+/// [`MvuLogic::view`] and before [`Renderer::render`], including for the initial
+/// render. This is the place for cross-cutting presentation concerns, such as
+/// theming or localization, that would otherwise be repeated in every `view`.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::PropsTransform;
+///
+/// struct Props { title: String }
+///
+/// struct Shout;
+///
+/// impl PropsTransform<Props> for Shout {
+///     fn transform(&self, props: Props) -> Props {
+///         Props { title: props.title.to_uppercase() }
+///     }
+/// }
+/// ```
+pub trait PropsTransform<Props> {
+    /// Rewrite the Props produced by `view`.
+    fn transform(&self, props: Props) -> Props;
+}
+
 /// The MVU runtime that orchestrates the event loop.
 ///
 /// This is the core of the framework. It:
@@ -58,21 +321,191 @@ where
 /// * `Spawn` - The spawner implementation type (implements [`Spawner`])
 pub struct MvuRuntime<Event, Model, Props, Logic, Render, Spawn>
 where
-    Event: Send,
-    Model: Clone,
+    Event: Send + 'static,
+    Model: Clone + 'static,
     Logic: MvuLogic<Event, Model, Props>,
     Render: Renderer<Props>,
     Spawn: Spawner,
 {
     logic: Logic,
     renderer: Render,
-    event_receiver: Receiver<Event>,
+    /// Shared with handles only weakly, so the event channel disconnects once
+    /// the runtime is dropped.
+    event_receiver: Arc<Receiver<Event>>,
     model: Model,
     emitter: Emitter<Event>,
     spawner: Spawn,
+    channel_counters: ChannelCounters,
+    last_view_key: Option<u64>,
+    secondary_views: Vec<SecondaryView<Event, Model>>,
+    started: bool,
+    next_event: Option<RecvFut<'static, Event>>,
+    command_sender: Sender<Command<Event, Model>>,
+    command_receiver: Receiver<Command<Event, Model>>,
+    next_command: Option<RecvFut<'static, Command<Event, Model>>>,
+    coalescer: Option<Box<dyn Coalescer<Event> + Send>>,
+    batch_update: Option<BatchUpdate<Event, Model, Logic>>,
+    render_timing: Option<RenderTiming>,
+    render_cause: Option<RenderCause>,
+    render_scheduler: Option<RenderScheduler>,
+    props_transforms: Vec<Box<dyn PropsTransform<Props> + Send>>,
+    model_observers: Vec<ModelObserver<Model>>,
+    rendering_suspended: bool,
+    init_render_mode: InitRenderMode,
+    initial_render_pending: bool,
+    tick_event: Option<Box<dyn Fn(Duration) -> Event + Send>>,
+    event_hooks: Vec<EventHook<Event>>,
+    emit_loop_detector: Option<EmitLoopDetector<Event>>,
+    on_effect_fanout: Option<Arc<EffectFanoutHook>>,
+    strict_view: Option<StrictView<Event>>,
+    #[cfg(not(feature = "no_std"))]
+    idle: Arc<IdleSignal>,
+    #[cfg(not(feature = "no_std"))]
+    panic_policy: Option<PanicPolicy>,
+    #[cfg(not(feature = "no_std"))]
+    error_boundary: Option<Box<dyn ErrorBoundary<Event, Model> + Send>>,
+    #[cfg(not(feature = "no_std"))]
+    stage: CycleStage,
+    shut_down: bool,
+    #[cfg(any(test, feature = "testing"))]
+    view_count: usize,
     _props: core::marker::PhantomData<Props>,
 }
 
+/// A registered secondary view that projects the model and renders the result.
+type SecondaryView<Event, Model> = Box<dyn FnMut(&Model, &Emitter<Event>) + Send>;
+
+/// Render duration tracking configured via [`MvuRuntime::with_render_timing`].
+struct RenderTiming {
+    clock: Box<dyn Clock + Send>,
+    slow_render_threshold: Duration,
+    on_slow_render: Box<dyn FnMut(Duration) + Send>,
+    last_render_duration: Option<Duration>,
+}
+
+/// [`BatchMvuLogic::update_batch`] of the runtime's logic, configured via
+/// [`MvuRuntime::with_batch_update`].
+type BatchUpdate<Event, Model, Logic> = fn(&Logic, Vec<Event>, &Model) -> (Model, Effect<Event>);
+
+/// Render-cause reporting configured via [`MvuRuntime::with_render_cause`].
+struct RenderCause {
+    on_render_cause: Box<RenderCauseHook>,
+    last_deps: Option<Vec<(&'static str, u64)>>,
+}
+
+/// Hook called with the names of the view dependencies that changed.
+type RenderCauseHook = dyn FnMut(&[&'static str]) + Send;
+
+/// Emit loop detection configured via [`MvuRuntime::with_emit_loop_detection`].
+struct EmitLoopDetector<Event> {
+    threshold: usize,
+    on_emit_loop_detected: Box<EmitLoopHook<Event>>,
+    same: fn(&Event, &Event) -> bool,
+    copy: fn(&Event) -> Event,
+    previous: Option<Event>,
+    depth: usize,
+}
+
+/// Hook called with the event continuing an emit loop and the loop's depth.
+type EmitLoopHook<Event> = dyn FnMut(&Event, usize) + Send;
+
+impl<Event> EmitLoopDetector<Event> {
+    /// Count `event` towards the current run of identical events, returning
+    /// whether the run has reached the threshold.
+    fn detects(&mut self, event: &Event) -> bool {
+        match &self.previous {
+            Some(previous) if (self.same)(previous, event) => self.depth += 1,
+            _ => {
+                self.previous = Some((self.copy)(event));
+                self.depth = 1;
+            }
+        }
+
+        if self.depth < self.threshold {
+            return false;
+        }
+
+        (self.on_emit_loop_detected)(event, self.depth);
+        self.reset();
+        true
+    }
+
+    /// Start counting afresh, at the start of each drain.
+    fn reset(&mut self) {
+        self.previous = None;
+        self.depth = 0;
+    }
+}
+
+/// Hook configured via [`MvuRuntime::with_effect_fanout`].
+type EffectFanoutHook = dyn Fn(Option<&'static str>, usize) + Send + Sync;
+
+/// Emitter handed to views in strict mode, configured via
+/// [`MvuRuntime::with_strict_view`].
+struct StrictView<Event: Send> {
+    emitter: Emitter<Event>,
+    in_view: Arc<ViewPhase>,
+}
+
+/// Tracks whether a view is being computed, and on std by which thread, so
+/// that emits from other threads during a view are not rejected.
+#[derive(Default)]
+struct ViewPhase {
+    #[cfg(not(feature = "no_std"))]
+    thread: Mutex<Option<std::thread::ThreadId>>,
+    #[cfg(feature = "no_std")]
+    active: AtomicBool,
+}
+
+impl ViewPhase {
+    /// Enter the view phase on the current thread until the guard is dropped,
+    /// including when the view panics.
+    fn enter(&self) -> ViewPhaseGuard<'_> {
+        #[cfg(not(feature = "no_std"))]
+        {
+            *self.thread.lock() = Some(std::thread::current().id());
+        }
+        #[cfg(feature = "no_std")]
+        self.active.store(true, Ordering::Release);
+        ViewPhaseGuard(self)
+    }
+
+    /// Whether the caller is emitting from within the view being computed.
+    fn is_active(&self) -> bool {
+        #[cfg(not(feature = "no_std"))]
+        {
+            *self.thread.lock() == Some(std::thread::current().id())
+        }
+        #[cfg(feature = "no_std")]
+        {
+            self.active.load(Ordering::Acquire)
+        }
+    }
+}
+
+/// Leaves the view phase when dropped.
+struct ViewPhaseGuard<'a>(&'a ViewPhase);
+
+impl Drop for ViewPhaseGuard<'_> {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "no_std"))]
+        {
+            *self.0.thread.lock() = None;
+        }
+        #[cfg(feature = "no_std")]
+        self.0.active.store(false, Ordering::Release);
+    }
+}
+
+/// Callback that triggers a scheduled render when invoked by the host.
+type ScheduledRender = Box<dyn FnOnce() + Send>;
+
+/// Host callback configured via [`MvuRuntime::with_render_scheduler`].
+struct RenderScheduler {
+    schedule: Box<dyn Fn(ScheduledRender) + Send>,
+    scheduled: bool,
+}
+
 impl<Event, Model, Props, Logic, Render, Spawn>
     MvuRuntime<Event, Model, Props, Logic, Render, Spawn>
 where
@@ -85,87 +518,1019 @@ where
 {
     /// Create a new runtime.
     ///
-    /// The runtime will not be started until MvuRuntime::run is called.
+    /// The runtime will not be started until MvuRuntime::run is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `init_model` - The initial state
+    /// * `logic` - Application logic implementing MvuLogic
+    /// * `renderer` - Platform rendering implementation for rendering Props
+    /// * `spawner` - Spawner to execute async effects on your chosen runtime
+    pub fn new(init_model: Model, logic: Logic, renderer: Render, spawner: Spawn) -> Self {
+        let (event_sender, event_receiver) = flume::unbounded();
+        let emitter = Emitter::new(event_sender);
+        let (command_sender, command_receiver) = flume::unbounded();
+
+        MvuRuntime {
+            logic,
+            renderer,
+            event_receiver: Arc::new(event_receiver),
+            model: init_model,
+            emitter,
+            spawner,
+            channel_counters: ChannelCounters::default(),
+            last_view_key: None,
+            secondary_views: Vec::new(),
+            started: false,
+            next_event: None,
+            command_sender,
+            command_receiver,
+            next_command: None,
+            coalescer: None,
+            batch_update: None,
+            render_timing: None,
+            render_cause: None,
+            render_scheduler: None,
+            props_transforms: Vec::new(),
+            model_observers: Vec::new(),
+            rendering_suspended: false,
+            init_render_mode: InitRenderMode::default(),
+            initial_render_pending: false,
+            tick_event: None,
+            event_hooks: Vec::new(),
+            emit_loop_detector: None,
+            on_effect_fanout: None,
+            strict_view: None,
+            #[cfg(not(feature = "no_std"))]
+            idle: Arc::new(IdleSignal::default()),
+            #[cfg(not(feature = "no_std"))]
+            panic_policy: None,
+            #[cfg(not(feature = "no_std"))]
+            error_boundary: None,
+            #[cfg(not(feature = "no_std"))]
+            stage: CycleStage::Update,
+            shut_down: false,
+            #[cfg(any(test, feature = "testing"))]
+            view_count: 0,
+            _props: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a new runtime whose renderer is safe to use from other threads.
+    ///
+    /// Identical to [`new`](Self::new), except the renderer must be `Send + Sync`.
+    /// Use this when the runtime will be driven on a multi-threaded executor or
+    /// the renderer is shared with other threads, so that a renderer holding
+    /// thread-bound state (such as an `Rc`) is rejected here rather than at a
+    /// distant spawn call. Single-threaded hosts such as wasm or embedded targets
+    /// should keep using `new`.
+    ///
+    /// ```compile_fail
+    /// # use oxide_mvu::{Emitter, Effect, MvuLogic, MvuRuntime, Renderer};
+    /// # use std::rc::Rc;
+    /// # #[derive(Clone)] enum Event {}
+    /// # #[derive(Clone)] struct Model;
+    /// # struct Logic;
+    /// # impl MvuLogic<Event, Model, ()> for Logic {
+    /// #     fn init(&self, m: Model) -> (Model, Effect<Event>) { (m, Effect::none()) }
+    /// #     fn update(&self, _: Event, m: &Model) -> (Model, Effect<Event>) { (m.clone(), Effect::none()) }
+    /// #     fn view(&self, _: &Model, _: &Emitter<Event>) {}
+    /// # }
+    /// struct LocalRenderer(Rc<()>);
+    /// impl Renderer<()> for LocalRenderer { fn render(&mut self, _: ()) {} }
+    ///
+    /// // Rc is neither Send nor Sync
+    /// let runtime = MvuRuntime::new_threadsafe(Model, Logic, LocalRenderer(Rc::new(())), |_| {});
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `init_model` - The initial state
+    /// * `logic` - Application logic implementing MvuLogic
+    /// * `renderer` - Thread-safe rendering implementation for rendering Props
+    /// * `spawner` - Spawner to execute async effects on your chosen runtime
+    pub fn new_threadsafe(init_model: Model, logic: Logic, renderer: Render, spawner: Spawn) -> Self
+    where
+        Render: Send + Sync,
+    {
+        Self::new(init_model, logic, renderer, spawner)
+    }
+
+    /// Register an additional view rendered alongside the primary one.
+    ///
+    /// On every model change, `project` reduces the model to `OtherProps` which
+    /// are then delivered to `renderer`. This allows a single program to drive
+    /// several surfaces (e.g. a main view and a minimap), each with its own
+    /// Props type.
+    ///
+    /// Secondary views are rendered after the primary view, in registration order,
+    /// and are not affected by [`MvuLogic::view_key`].
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - Reduces the model to the secondary Props
+    /// * `renderer` - Renderer receiving the secondary Props
+    pub fn add_view<OtherProps, F>(
+        &mut self,
+        project: F,
+        mut renderer: Box<dyn Renderer<OtherProps> + Send>,
+    ) where
+        OtherProps: 'static,
+        F: Fn(&Model, &Emitter<Event>) -> OtherProps + Send + 'static,
+    {
+        self.secondary_views
+            .push(Box::new(move |model: &Model, emitter: &Emitter<Event>| {
+                renderer.render(project(model, emitter));
+            }));
+    }
+
+    /// Coalesce each batch of queued events before it is processed.
+    ///
+    /// See [`Coalescer`].
+    pub fn with_coalescer<C>(mut self, coalescer: C) -> Self
+    where
+        C: Coalescer<Event> + Send + 'static,
+    {
+        self.coalescer = Some(Box::new(coalescer));
+        self
+    }
+
+    /// Panic when an event is emitted while a view is being computed.
+    ///
+    /// Emitting from within [`MvuLogic::view`] rather than from a callback in the
+    /// returned Props is almost always a bug, and can cause render loops. In
+    /// strict mode the emitter handed to views panics with a clear message when
+    /// used during the view call, while emits from the Props callbacks it was
+    /// captured in keep working. Views registered with [`add_view`](Self::add_view)
+    /// are not checked.
+    ///
+    /// Only emits from the thread computing the view are rejected, so a Props
+    /// callback fired on another thread while a view is being computed still
+    /// works. Under `no_std` threads can't be told apart, and any emit through
+    /// the view's emitter during a view panics.
+    pub fn with_strict_view(mut self) -> Self {
+        let in_view = Arc::new(ViewPhase::default());
+        let emitter = {
+            let emitter = self.emitter.clone();
+            let in_view = in_view.clone();
+            Emitter::from_sink(move |event| {
+                if in_view.is_active() {
+                    panic!(
+                        "event emitted during view; emit from Props callbacks or effects instead"
+                    );
+                }
+                emitter.emit(event);
+            })
+        };
+        self.strict_view = Some(StrictView { emitter, in_view });
+        self
+    }
+
+    /// Report how many events each effect emitted.
+    ///
+    /// Once an effect's future completes, `on_effect_fanout` is called with the
+    /// effect's [`label`](Effect::label) and the number of events it emitted.
+    /// Use it to find chatty effects, such as one accidentally emitting
+    /// hundreds of events. The hook runs wherever the spawner polls effects.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_effect_fanout` - Called with the label and event count of each completed effect
+    pub fn with_effect_fanout<F>(mut self, on_effect_fanout: F) -> Self
+    where
+        F: Fn(Option<&'static str>, usize) + Send + Sync + 'static,
+    {
+        let on_effect_fanout: Box<EffectFanoutHook> = Box::new(on_effect_fanout);
+        self.on_effect_fanout = Some(Arc::from(on_effect_fanout));
+        self
+    }
+
+    /// Decide what happens when processing an event panics.
+    ///
+    /// Only available without the `no_std` feature. Without a policy, panics
+    /// unwind out of the event loop. See [`PanicPolicy`].
+    #[cfg(not(feature = "no_std"))]
+    pub fn with_panic_policy(mut self, policy: PanicPolicy) -> Self {
+        self.panic_policy = Some(policy);
+        self
+    }
+
+    /// Route failures while processing events through `boundary`.
+    ///
+    /// Only available without the `no_std` feature. Takes precedence over a
+    /// [`PanicPolicy`]. A model set by the boundary is rendered outside of it,
+    /// so a view that keeps panicking unwinds out of the event loop. See
+    /// [`ErrorBoundary`].
+    #[cfg(not(feature = "no_std"))]
+    pub fn with_error_boundary<B>(mut self, boundary: B) -> Self
+    where
+        B: ErrorBoundary<Event, Model> + Send + 'static,
+    {
+        self.error_boundary = Some(Box::new(boundary));
+        self
+    }
+
+    /// Choose whether the initial render happens before or after the initial effect.
+    ///
+    /// With [`InitRenderMode::AfterEffects`], the first render is deferred until
+    /// the event loop has processed every event available after spawning the
+    /// initial effect, i.e. the first time it runs out of work. Events emitted
+    /// later by slow async effects are rendered as usual.
+    pub fn with_init_render_mode(mut self, mode: InitRenderMode) -> Self {
+        self.init_render_mode = mode;
+        self
+    }
+
+    /// Process queued events in batches with [`BatchMvuLogic::update_batch`].
+    ///
+    /// Each time the runtime wakes up it drains all queued events, coalesces them
+    /// if a [`Coalescer`] is registered, and reduces the batch with a single
+    /// `update_batch` call followed by a single render. Events keep the order in
+    /// which they were emitted. One-shot event hooks still see every event.
+    pub fn with_batch_update(mut self) -> Self
+    where
+        Logic: BatchMvuLogic<Event, Model, Props>,
+    {
+        self.batch_update = Some(<Logic as BatchMvuLogic<Event, Model, Props>>::update_batch);
+        self
+    }
+
+    /// Transform Props after `view` and before they are rendered.
+    ///
+    /// Transforms are applied in registration order. See [`PropsTransform`].
+    pub fn with_props_transform<T>(mut self, transform: T) -> Self
+    where
+        T: PropsTransform<Props> + Send + 'static,
+    {
+        self.props_transforms.push(Box::new(transform));
+        self
+    }
+
+    /// Measure how long each render takes.
+    ///
+    /// Every call to the renderer is timed with `clock`. The most recent duration is
+    /// available from [`last_render_duration`](Self::last_render_duration), and
+    /// `on_slow_render` is invoked with the duration whenever a render takes longer
+    /// than `slow_render_threshold`.
+    ///
+    /// Only the primary renderer is timed; views registered with
+    /// [`add_view`](Self::add_view) are not.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - Time source used to measure renders
+    /// * `slow_render_threshold` - Renders taking longer than this are reported
+    /// * `on_slow_render` - Called with the duration of each slow render
+    pub fn with_render_timing<C, F>(
+        mut self,
+        clock: C,
+        slow_render_threshold: Duration,
+        on_slow_render: F,
+    ) -> Self
+    where
+        C: Clock + Send + 'static,
+        F: FnMut(Duration) + Send + 'static,
+    {
+        self.render_timing = Some(RenderTiming {
+            clock: Box::new(clock),
+            slow_render_threshold,
+            on_slow_render: Box::new(on_slow_render),
+            last_render_duration: None,
+        });
+        self
+    }
+
+    /// Report which model fields changed before each render.
+    ///
+    /// Before every render after the first, `on_render_cause` is called with the
+    /// names of the fields declared by [`MvuLogic::view_deps`] whose key differs
+    /// from the previous render. An empty list means the render was caused by a
+    /// field `view_deps` doesn't declare. Renders skipped by
+    /// [`MvuLogic::view_key`] are not reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_render_cause` - Called with the names of the changed fields
+    pub fn with_render_cause<F>(mut self, on_render_cause: F) -> Self
+    where
+        F: FnMut(&[&'static str]) + Send + 'static,
+    {
+        self.render_cause = Some(RenderCause {
+            on_render_cause: Box::new(on_render_cause),
+            last_deps: None,
+        });
+        self
+    }
+
+    /// Let the host decide when renders happen.
+    ///
+    /// Instead of rendering after every model change, the runtime hands `schedule`
+    /// a callback and renders only once the host invokes it, for example from
+    /// inside `requestAnimationFrame`. Model changes made before the callback
+    /// runs are coalesced into a single render of the latest model, and at most
+    /// one callback is outstanding at a time.
+    ///
+    /// The render itself happens on the event loop, so the runtime must keep
+    /// being polled for a scheduled render to take effect. Both `schedule` and
+    /// the callback it receives are `Send`, so a runtime with a scheduler can
+    /// still be spawned onto another thread.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use oxide_mvu::{Emitter, Effect, MvuLogic, MvuRuntime, Renderer};
+    /// # #[derive(Clone)] enum Event {}
+    /// # #[derive(Clone)] struct Model;
+    /// # struct Logic;
+    /// # impl MvuLogic<Event, Model, ()> for Logic {
+    /// #     fn init(&self, m: Model) -> (Model, Effect<Event>) { (m, Effect::none()) }
+    /// #     fn update(&self, _: Event, m: &Model) -> (Model, Effect<Event>) { (m.clone(), Effect::none()) }
+    /// #     fn view(&self, _: &Model, _: &Emitter<Event>) {}
+    /// # }
+    /// # struct NoopRenderer;
+    /// # impl Renderer<()> for NoopRenderer { fn render(&mut self, _: ()) {} }
+    /// # fn request_animation_frame(_: Box<dyn FnOnce() + Send>) {}
+    /// let runtime = MvuRuntime::new(Model, Logic, NoopRenderer, |_| {})
+    ///     .with_render_scheduler(|render| request_animation_frame(render));
+    /// ```
+    pub fn with_render_scheduler<F>(mut self, schedule: F) -> Self
+    where
+        F: Fn(Box<dyn FnOnce() + Send>) + Send + 'static,
+    {
+        self.render_scheduler = Some(RenderScheduler {
+            schedule: Box::new(schedule),
+            scheduled: false,
+        });
+        self
+    }
+
+    /// Detect events that keep re-triggering themselves.
+    ///
+    /// A common mistake is an `update` whose effect emits the very event being
+    /// handled, which loops forever. With detection enabled, the runtime counts
+    /// how many identical events are processed in a row within a single drain,
+    /// i.e. one wake-up of the event loop processing everything available. When
+    /// the count reaches `threshold`, `on_emit_loop_detected` is called with the
+    /// event and count, and that event is dropped along with every event still
+    /// queued, breaking the loop. Identical events handled in separate drains,
+    /// such as repeated clicks, are never counted together.
+    ///
+    /// With [`with_coalescer`](Self::with_coalescer) or
+    /// [`with_batch_update`](Self::with_batch_update), events are counted as
+    /// they are drained into a batch, before coalescing.
+    ///
+    /// Intended for development builds; legitimate bursts of identical events
+    /// longer than `threshold` are also cut short.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - Number of identical consecutive events treated as a loop
+    /// * `on_emit_loop_detected` - Called with the offending event and the count
+    pub fn with_emit_loop_detection<F>(mut self, threshold: usize, on_emit_loop_detected: F) -> Self
+    where
+        Event: PartialEq + Clone,
+        F: FnMut(&Event, usize) + Send + 'static,
+    {
+        self.emit_loop_detector = Some(EmitLoopDetector {
+            threshold,
+            on_emit_loop_detected: Box::new(on_emit_loop_detected),
+            same: Event::eq,
+            copy: Event::clone,
+            previous: None,
+            depth: 0,
+        });
+        self
+    }
+
+    /// Configure the event emitted by [`tick`](Self::tick) and [`RuntimeHandle::tick`].
+    ///
+    /// `tick_event` builds the event from the timestep passed to `tick`.
+    pub fn with_tick_event<F>(mut self, tick_event: F) -> Self
+    where
+        F: Fn(Duration) -> Event + Send + 'static,
+    {
+        self.tick_event = Some(Box::new(tick_event));
+        self
+    }
+
+    /// Advance the program by a fixed timestep.
+    ///
+    /// For game-style hosts that drive the runtime from their own frame loop
+    /// instead of awaiting [`run`](Self::run). Emits the event configured with
+    /// [`with_tick_event`](Self::with_tick_event) for `dt`, then synchronously
+    /// processes every queued event, including the tick, before returning. The
+    /// settled model is rendered once for the whole tick. The first call also
+    /// initializes the runtime, as [`poll_once`](Self::poll_once) does.
+    ///
+    /// Without a tick event configured, this only processes queued events.
+    /// To tick from elsewhere, use [`RuntimeHandle::tick`].
+    pub fn tick(&mut self, dt: Duration) {
+        self.command_sender.send(Command::Tick(dt)).ok();
+
+        let _ = self.poll_once(&mut Context::from_waker(&noop_waker()));
+    }
+
+    /// Duration of the most recent render.
+    ///
+    /// Returns `None` until a render has completed with timing enabled via
+    /// [`with_render_timing`](Self::with_render_timing).
+    pub fn last_render_duration(&self) -> Option<Duration> {
+        self.render_timing
+            .as_ref()
+            .and_then(|timing| timing.last_render_duration)
+    }
+
+    /// Get a handle for interacting with this runtime from outside the event loop.
+    ///
+    /// The handle remains valid after the runtime is consumed by
+    /// [`MvuRuntime::run`].
+    pub fn handle(&self) -> RuntimeHandle<Event, Model> {
+        RuntimeHandle::new(
+            self.emitter.clone(),
+            Arc::downgrade(&self.event_receiver),
+            self.command_sender.clone(),
+            self.channel_counters.clone(),
+            #[cfg(not(feature = "no_std"))]
+            self.idle.clone(),
+        )
+    }
+
+    /// Initialize the runtime and run the event processing loop.
+    ///
+    /// - Uses the MvuLogic::init function to create and enqueue initial side effects.
+    /// - Reduces the initial Model provided at construction to Props via MvuLogic::view.
+    /// - Renders the initial Props.
+    /// - Processes events from the channel in a loop.
+    ///
+    /// This is an async function that runs the event loop. You can spawn it on your
+    /// chosen runtime using the spawner, or await it directly.
+    ///
+    /// Events can be emitted from any thread via the Emitter, but are always processed
+    /// sequentially on the thread where this future is awaited/polled.
+    pub async fn run(mut self) {
+        core::future::poll_fn(move |cx| self.poll_once(cx)).await
+    }
+
+    /// Process all currently available events without blocking.
+    ///
+    /// This is the low-level primitive underlying [`MvuRuntime::run`], for embedding
+    /// the event loop inside your own future or task. The first call initializes the
+    /// runtime and renders the initial Props, just as `run` does.
+    ///
+    /// Returns [`Poll::Pending`] once the queue is empty, after registering the
+    /// waker from `cx` to be woken when the next event is emitted. Returns
+    /// [`Poll::Ready`] only if the event channel has been closed.
+    pub fn poll_once(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        #[cfg(not(feature = "no_std"))]
+        self.idle.set_processing(true);
+
+        let poll = self.poll_events(cx);
+
+        #[cfg(not(feature = "no_std"))]
+        self.idle.set_processing(false);
+
+        poll
+    }
+
+    /// Process commands and events until the queues are empty or closed.
+    fn poll_events(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        if !self.started {
+            self.start();
+        }
+        self.start_drain();
+
+        loop {
+            self.spawner.poll_spawned(cx);
+
+            let next_command = self
+                .next_command
+                .get_or_insert_with(|| self.command_receiver.clone().into_recv_async());
+
+            if let Poll::Ready(Ok(command)) = Pin::new(next_command).poll(cx) {
+                self.next_command = None;
+                self.apply_command(command);
+                continue;
+            }
+
+            let next_event = self
+                .next_event
+                .get_or_insert_with(|| Receiver::clone(&self.event_receiver).into_recv_async());
+
+            match Pin::new(next_event).poll(cx) {
+                Poll::Ready(Ok(event)) => {
+                    self.next_event = None;
+                    self.process_available(event);
+                    if self.shut_down {
+                        return Poll::Ready(());
+                    }
+                }
+                Poll::Ready(Err(_)) => return Poll::Ready(()),
+                Poll::Pending => {
+                    self.finish_initial_render();
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+
+    /// Initialize the model, render the initial Props and spawn the initial effect.
+    fn start(&mut self) {
+        let init_effect = self.init();
+
+        // Execute initial effect by spawning it
+        self.spawn_effect(init_effect);
+    }
+
+    /// Initialize the model and render the initial Props, returning the initial
+    /// effect for the caller to spawn.
+    fn init(&mut self) -> Effect<Event> {
+        self.started = true;
+
+        let (init_model, init_effect) = self.logic.init(self.model.clone());
+
+        match self.init_render_mode {
+            InitRenderMode::BeforeEffects => self.render(&init_model),
+            InitRenderMode::AfterEffects => self.initial_render_pending = true,
+        }
+        self.commit(init_model);
+
+        init_effect
+    }
+
+    /// Execute `effect` on the spawner, counting its events if fan-out is observed.
+    fn spawn_effect(&mut self, effect: Effect<Event>) {
+        let future = match &self.on_effect_fanout {
+            None => effect.execute(&self.emitter),
+            Some(on_effect_fanout) => {
+                let on_effect_fanout = on_effect_fanout.clone();
+                let label = effect.label();
+                let emitted = Arc::new(AtomicUsize::new(0));
+                let emitter = {
+                    let emitter = self.emitter.clone();
+                    let emitted = emitted.clone();
+                    Emitter::from_sink(move |event| {
+                        emitted.fetch_add(1, Ordering::Relaxed);
+                        emitter.emit(event);
+                    })
+                };
+                let future = effect.execute(&emitter);
+                Box::pin(async move {
+                    future.await;
+                    on_effect_fanout(label, emitted.load(Ordering::Relaxed));
+                })
+            }
+        };
+
+        // Track the effect until it completes, for `RuntimeHandle::wait_idle`
+        #[cfg(not(feature = "no_std"))]
+        let future = {
+            let in_flight = EffectInFlight::new(&self.idle);
+            Box::pin(async move {
+                let _in_flight = in_flight;
+                future.await;
+            })
+        };
+
+        self.spawner.spawn(future);
+    }
+
+    /// Render the settled model if the initial render was deferred until after
+    /// the initial effect.
+    fn finish_initial_render(&mut self) {
+        if self.initial_render_pending {
+            self.initial_render_pending = false;
+
+            let model = self.model.clone();
+            self.render(&model);
+        }
+    }
+
+    /// Apply all commands sent from handles.
+    #[cfg(any(test, feature = "testing"))]
+    fn apply_queued_commands(&mut self) {
+        while let Ok(command) = self.command_receiver.try_recv() {
+            self.apply_command(command);
+        }
+    }
+
+    fn apply_command(&mut self, command: Command<Event, Model>) {
+        match command {
+            Command::UpdateModel(update) => {
+                let mut new_model = self.model.clone();
+                update(&mut new_model);
+
+                self.render(&new_model);
+                self.commit(new_model);
+            }
+            Command::Render => {
+                if let Some(scheduler) = &mut self.render_scheduler {
+                    scheduler.scheduled = false;
+                }
+
+                let model = self.model.clone();
+                self.render_now(&model);
+            }
+            Command::Observe(mut observer) => {
+                if observer(&self.model) {
+                    self.model_observers.push(observer);
+                }
+            }
+            Command::OnceEvent(hook) => self.event_hooks.push(hook),
+            Command::Tick(dt) => self.process_tick(dt),
+            Command::SuspendRendering => self.rendering_suspended = true,
+            Command::ResumeRendering => {
+                if self.rendering_suspended {
+                    // Commands overtake queued events, so process the events
+                    // emitted before resuming while still suspended
+                    self.process_queued();
+                    self.rendering_suspended = false;
+
+                    let model = self.model.clone();
+                    self.render(&model);
+                }
+            }
+        }
+    }
+
+    /// Emit the tick event for `dt`, then process every queued event and render
+    /// the settled model once.
+    fn process_tick(&mut self, dt: Duration) {
+        if let Some(tick_event) = &self.tick_event {
+            self.emitter.emit(tick_event(dt));
+        }
+        if self.event_receiver.is_empty() {
+            return;
+        }
+
+        // Hold renders for the whole drain. Commands aren't applied until it
+        // completes, so a suspension from a handle can't be lost in between.
+        let suspended = core::mem::replace(&mut self.rendering_suspended, true);
+        self.process_queued();
+        self.rendering_suspended = suspended;
+
+        let model = self.model.clone();
+        self.render(&model);
+    }
+
+    /// Process queued events until the queue is empty, without waiting for more.
+    fn process_queued(&mut self) {
+        while !self.shut_down {
+            let Ok(event) = self.event_receiver.try_recv() else {
+                break;
+            };
+            self.process_available(event);
+        }
+    }
+
+    /// Make `model` the current model and notify observers.
+    fn commit(&mut self, model: Model) {
+        self.model = model;
+        let model = &self.model;
+        self.model_observers.retain_mut(|observer| observer(model));
+    }
+
+    /// Process `first` and the events queued behind it under the panic policy.
+    fn process_available(&mut self, first: Event) {
+        if self.shut_down {
+            return;
+        }
+
+        #[cfg(not(feature = "no_std"))]
+        if self.panic_policy.is_some() || self.error_boundary.is_some() {
+            self.stage = CycleStage::Update;
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                self.process_batch(first);
+            }));
+            if let Err(panic) = result {
+                self.recover(panic);
+            }
+            return;
+        }
+
+        self.process_batch(first);
+    }
+
+    /// Handle a panic caught while processing events, with the error boundary
+    /// if there is one and the panic policy otherwise.
+    #[cfg(not(feature = "no_std"))]
+    fn recover(&mut self, panic: Box<dyn core::any::Any + Send>) {
+        let Some(boundary) = &self.error_boundary else {
+            match self.panic_policy {
+                Some(PanicPolicy::Abort) => std::process::abort(),
+                Some(PanicPolicy::Shutdown) => self.shut_down = true,
+                Some(PanicPolicy::SkipEvent) | None => {}
+            }
+            return;
+        };
+
+        let message = match (panic.downcast_ref::<&str>(), panic.downcast_ref::<String>()) {
+            (Some(message), _) => String::from(*message),
+            (_, Some(message)) => message.clone(),
+            _ => String::from("unknown panic"),
+        };
+        let error = match self.stage {
+            CycleStage::Update => RuntimeError::Update(message),
+            CycleStage::View => RuntimeError::View(message),
+            CycleStage::Render => RuntimeError::Render(message),
+        };
+
+        match boundary.handle(error, &self.model) {
+            ErrorAction::Emit(event) => self.emitter.emit(event),
+            ErrorAction::SetModel(model) => {
+                self.render(&model);
+                self.commit(model);
+            }
+            ErrorAction::Ignore => {}
+            ErrorAction::Shutdown => self.shut_down = true,
+        }
+    }
+
+    /// Process `first` along with any other events already queued behind it.
+    ///
+    /// Without a [`Coalescer`] or batch update each event is processed as it
+    /// arrives. Otherwise the queued events are drained into a batch and
+    /// coalesced before processing.
+    fn process_batch(&mut self, first: Event) {
+        if self.coalescer.is_none() && self.batch_update.is_none() {
+            if !self.detects_emit_loop(&first) {
+                self.step(first);
+            }
+            return;
+        }
+
+        let mut batch = Vec::with_capacity(1 + self.event_receiver.len());
+        batch.push(first);
+        batch.extend(self.event_receiver.try_iter());
+        if let Some(looping) = batch.iter().position(|event| self.detects_emit_loop(event)) {
+            batch.truncate(looping);
+        }
+        if let Some(coalescer) = &self.coalescer {
+            coalescer.coalesce(&mut batch);
+        }
+
+        match self.batch_update {
+            Some(update_batch) => self.step_batch(update_batch, batch),
+            None => {
+                for event in batch {
+                    self.step(event);
+                }
+            }
+        }
+    }
+
+    fn step_batch(&mut self, update_batch: BatchUpdate<Event, Model, Logic>, batch: Vec<Event>) {
+        if batch.is_empty() {
+            return;
+        }
+
+        // Run one-shot hooks, dropping those that fired
+        for event in &batch {
+            self.event_hooks.retain_mut(|hook| !hook(event));
+        }
+
+        let (new_model, effect) = update_batch(&self.logic, batch, &self.model);
+        self.apply_update(new_model, effect);
+    }
+
+    /// Reset per-drain state before processing the events available now.
+    fn start_drain(&mut self) {
+        if let Some(detector) = &mut self.emit_loop_detector {
+            detector.reset();
+        }
+    }
+
+    /// Whether `event` continues an emit loop, in which case every queued
+    /// event is dropped to break it.
+    fn detects_emit_loop(&mut self, event: &Event) -> bool {
+        let Some(detector) = &mut self.emit_loop_detector else {
+            return false;
+        };
+        if !detector.detects(event) {
+            return false;
+        }
+
+        drop(self.event_receiver.drain());
+        true
+    }
+
+    fn step(&mut self, event: Event) {
+        #[cfg(not(feature = "no_std"))]
+        {
+            self.stage = CycleStage::Update;
+        }
+
+        // Run one-shot hooks, dropping those that fired
+        self.event_hooks.retain_mut(|hook| !hook(&event));
+
+        // Update model with event
+        let (new_model, effect) = self.logic.update(event, &self.model);
+        self.apply_update(new_model, effect);
+    }
+
+    /// Render and commit an updated model, then execute its effect.
+    fn apply_update(&mut self, new_model: Model, effect: Effect<Event>) {
+        // Reduce to props and render
+        self.render(&new_model);
+
+        // Update model
+        self.commit(new_model);
+
+        // Execute the effect
+        self.spawn_effect(effect);
+    }
+
+    /// Render `model`, or schedule a render if a render scheduler is registered.
+    ///
+    /// Nothing is rendered while rendering is suspended or the initial render
+    /// is deferred.
+    ///
+    /// A scheduled render draws whatever the model is once the host runs the
+    /// callback, so `model` must be committed before the event loop resumes.
+    fn render(&mut self, model: &Model) {
+        if self.rendering_suspended || self.initial_render_pending {
+            return;
+        }
+
+        let Some(scheduler) = &mut self.render_scheduler else {
+            return self.render_now(model);
+        };
+
+        if !scheduler.scheduled {
+            scheduler.scheduled = true;
+            let command_sender = self.command_sender.clone();
+            (scheduler.schedule)(Box::new(move || {
+                command_sender.send(Command::Render).ok();
+            }));
+        }
+    }
+
+    /// Reduce the model to Props and render them, followed by any secondary views.
+    ///
+    /// The primary view is skipped when [`MvuLogic::view_key`] reports the same
+    /// key as the previously rendered model.
+    fn render_now(&mut self, model: &Model) {
+        let view_key = self.logic.view_key(model);
+        if view_key.is_none() || view_key != self.last_view_key {
+            self.last_view_key = view_key;
+            #[cfg(any(test, feature = "testing"))]
+            {
+                self.view_count += 1;
+            }
+            if let Some(cause) = &mut self.render_cause {
+                let deps = self.logic.view_deps(model);
+                if let Some(last_deps) = &cause.last_deps {
+                    let changed: Vec<&'static str> = deps
+                        .iter()
+                        .filter(|dep| !last_deps.contains(dep))
+                        .map(|(name, _)| *name)
+                        .collect();
+                    (cause.on_render_cause)(&changed);
+                }
+                cause.last_deps = Some(deps);
+            }
+
+            #[cfg(not(feature = "no_std"))]
+            {
+                self.stage = CycleStage::View;
+            }
+            let props = {
+                let _in_view = self
+                    .strict_view
+                    .as_ref()
+                    .map(|strict| strict.in_view.enter());
+                let emitter = self
+                    .strict_view
+                    .as_ref()
+                    .map_or(&self.emitter, |strict| &strict.emitter);
+                self.props_transforms
+                    .iter()
+                    .fold(self.logic.view(model, emitter), |props, transform| {
+                        transform.transform(props)
+                    })
+            };
+            #[cfg(not(feature = "no_std"))]
+            {
+                self.stage = CycleStage::Render;
+            }
+            match &mut self.render_timing {
+                None => self.renderer.render(props),
+                Some(timing) => {
+                    let start = timing.clock.now();
+                    self.renderer.render(props);
+                    let duration = timing.clock.now().saturating_sub(start);
+
+                    timing.last_render_duration = Some(duration);
+                    if duration > timing.slow_render_threshold {
+                        (timing.on_slow_render)(duration);
+                    }
+                }
+            }
+        }
+
+        #[cfg(not(feature = "no_std"))]
+        {
+            self.stage = CycleStage::Render;
+        }
+        for view in self.secondary_views.iter_mut() {
+            view(model, &self.emitter);
+        }
+    }
+}
+
+impl<Event, Model, Props, Logic, Render>
+    MvuRuntime<Event, Model, Props, Logic, Render, CooperativeSpawner>
+where
+    Event: Send + 'static,
+    Model: Clone + 'static,
+    Props: 'static,
+    Logic: MvuLogic<Event, Model, Props>,
+    Render: Renderer<Props>,
+{
+    /// Create a runtime that drives async effects itself.
+    ///
+    /// No async runtime or spawner is needed: effects are spawned on a built-in
+    /// [`CooperativeSpawner`] and polled by [`run`](Self::run) between processing
+    /// events, so [`Effect::from_async`](crate::Effect::from_async) works out of
+    /// the box. `run` still has to be awaited or polled by the host, e.g. with a
+    /// minimal `block_on`.
+    ///
+    /// # Blocking
+    ///
+    /// Effects run on the event loop's own thread. A future that blocks, for
+    /// example on synchronous file or network I/O, stalls event processing and
+    /// rendering until it returns. Only use this with non-blocking futures.
     ///
     /// # Arguments
     ///
     /// * `init_model` - The initial state
     /// * `logic` - Application logic implementing MvuLogic
     /// * `renderer` - Platform rendering implementation for rendering Props
-    /// * `spawner` - Spawner to execute async effects on your chosen runtime
-    pub fn new(init_model: Model, logic: Logic, renderer: Render, spawner: Spawn) -> Self {
-        let (event_sender, event_receiver) = flume::unbounded();
-        let emitter = Emitter::new(event_sender);
-
-        MvuRuntime {
-            logic,
-            renderer,
-            event_receiver,
-            model: init_model,
-            emitter,
-            spawner,
-            _props: core::marker::PhantomData,
-        }
+    pub fn new_cooperative(init_model: Model, logic: Logic, renderer: Render) -> Self {
+        Self::new(init_model, logic, renderer, CooperativeSpawner::new())
     }
+}
 
-    /// Initialize the runtime and run the event processing loop.
-    ///
-    /// - Uses the MvuLogic::init function to create and enqueue initial side effects.
-    /// - Reduces the initial Model provided at construction to Props via MvuLogic::view.
-    /// - Renders the initial Props.
-    /// - Processes events from the channel in a loop.
-    ///
-    /// This is an async function that runs the event loop. You can spawn it on your
-    /// chosen runtime using the spawner, or await it directly.
-    ///
-    /// Events can be emitted from any thread via the Emitter, but are always processed
-    /// sequentially on the thread where this future is awaited/polled.
-    pub async fn run(mut self) {
-        let (init_model, init_effect) = self.logic.init(self.model.clone());
-
-        let initial_props = {
-            let emitter = &self.emitter;
-            self.logic.view(&init_model, emitter)
-        };
-
-        self.renderer.render(initial_props);
-
-        // Execute initial effect by spawning it
-        let emitter = self.emitter.clone();
-        let future = init_effect.execute(&emitter);
-        self.spawner.spawn(Box::pin(future));
+#[cfg(any(test, feature = "testing"))]
+/// Minimal executor that polls a single future to completion on the current thread.
+///
+/// Only available with the `testing` feature or during tests. Works with `no_std`
+/// (only `alloc` is required), so async effects can be driven in tests without an
+/// external async runtime. This is the executor used by [`create_test_spawner`]
+/// under `no_std`, where the thread can't be parked while waiting.
+///
+/// The future is polled with a waker that does nothing, and is simply polled again
+/// whenever it returns [`Poll::Pending`]. It therefore assumes the future becomes
+/// ready on its own: a future that never resolves will spin forever.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::block_on;
+///
+/// assert_eq!(block_on(async { 21 * 2 }), 42);
+/// ```
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = core::pin::pin!(future);
 
-        // Event processing loop
-        while let Ok(event) = self.event_receiver.recv_async().await {
-            self.step(event)
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => core::hint::spin_loop(),
         }
     }
+}
 
-    fn step(&mut self, event: Event) {
-        // Update model with event
-        let (new_model, effect) = self.logic.update(event, &self.model);
+/// Create a waker that does nothing when woken.
+///
+/// Used wherever a future is polled synchronously, without an executor to wake.
+pub(crate) fn noop_waker() -> Waker {
+    use core::task::{RawWaker, RawWakerVTable};
 
-        // Reduce to props and render
-        let props = self.logic.view(&new_model, &self.emitter);
-        self.renderer.render(props);
+    fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(core::ptr::null(), &VTABLE)
+    }
+    fn noop(_: *const ()) {}
 
-        // Update model
-        self.model = new_model;
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
 
-        // Execute the effect
-        let emitter = self.emitter.clone();
-        let future = effect.execute(&emitter);
-        self.spawner.spawn(Box::pin(future));
-    }
+    // SAFETY: the vtable functions ignore the data pointer, so any pointer is valid.
+    unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) }
 }
 
 #[cfg(any(test, feature = "testing"))]
 /// Test spawner function that executes futures synchronously.
 ///
 /// This blocks on the future immediately rather than spawning it on an async runtime.
+/// The thread is parked until the future is woken, except under `no_std`, where
+/// the future is driven by the spinning [`block_on`] instead.
 pub fn test_spawner_fn(fut: Pin<Box<dyn Future<Output = ()> + Send>>) {
     // Execute the future synchronously for deterministic testing
+    #[cfg(not(feature = "no_std"))]
     futures::executor::block_on(fut);
+    #[cfg(feature = "no_std")]
+    block_on(fut);
 }
 
 #[cfg(any(test, feature = "testing"))]
@@ -181,6 +1546,145 @@ pub fn create_test_spawner() -> fn(Pin<Box<dyn Future<Output = ()> + Send>>) {
     test_spawner_fn
 }
 
+#[cfg(any(test, feature = "testing"))]
+/// Run [`MvuLogic::init`] in isolation and capture the events it produces.
+///
+/// Only available with the `testing` feature or during tests.
+///
+/// The effect returned from `init` is executed synchronously (as with
+/// [`create_test_spawner`]) against a capturing emitter, so both the initialized
+/// model and the bootstrap events can be asserted without running a runtime.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{inspect_init, Effect, Emitter, MvuLogic};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Event { Load }
+///
+/// struct Logic;
+///
+/// impl MvuLogic<Event, i32, ()> for Logic {
+///     fn init(&self, model: i32) -> (i32, Effect<Event>) { (model + 1, Effect::just(Event::Load)) }
+///     fn update(&self, _: Event, model: &i32) -> (i32, Effect<Event>) { (*model, Effect::none()) }
+///     fn view(&self, _: &i32, _: &Emitter<Event>) {}
+/// }
+///
+/// let (model, events) = inspect_init(&Logic, 0);
+///
+/// assert_eq!(model, 1);
+/// assert_eq!(events, vec![Event::Load]);
+/// ```
+pub fn inspect_init<Event, Model, Props, Logic>(logic: &Logic, model: Model) -> (Model, Vec<Event>)
+where
+    Event: Send + 'static,
+    Logic: MvuLogic<Event, Model, Props>,
+{
+    let (event_sender, event_receiver) = flume::unbounded();
+    let (init_model, init_effect) = logic.init(model);
+
+    test_spawner_fn(init_effect.execute(&Emitter::new(event_sender)));
+
+    (init_model, event_receiver.drain().collect())
+}
+
+#[cfg(any(test, feature = "testing"))]
+/// Run [`MvuLogic::update`] in isolation and describe the effect it returns.
+///
+/// Only available with the `testing` feature or during tests.
+///
+/// The effect is not executed. Instead it is returned as an
+/// [`EffectDescription`], so the exact shape of the effect an update produces
+/// can be asserted without side effects.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{dry_update, Effect, EffectDescription, Emitter, MvuLogic};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Event { Save }
+///
+/// struct Logic;
+///
+/// impl MvuLogic<Event, i32, ()> for Logic {
+///     fn init(&self, model: i32) -> (i32, Effect<Event>) { (model, Effect::none()) }
+///     fn update(&self, _: Event, model: &i32) -> (i32, Effect<Event>) {
+///         (*model, Effect::just(Event::Save).labeled("save"))
+///     }
+///     fn view(&self, _: &i32, _: &Emitter<Event>) {}
+/// }
+///
+/// let (model, effect) = dry_update(&Logic, Event::Save, &1);
+///
+/// assert_eq!(model, 1);
+/// assert_eq!(
+///     effect,
+///     EffectDescription::Labeled("save", Box::new(EffectDescription::Just(Event::Save)))
+/// );
+/// ```
+pub fn dry_update<Event, Model, Props, Logic>(
+    logic: &Logic,
+    event: Event,
+    model: &Model,
+) -> (Model, EffectDescription<Event>)
+where
+    Event: Send + 'static,
+    Logic: MvuLogic<Event, Model, Props>,
+{
+    let (model, effect) = logic.update(event, model);
+    (model, effect.describe())
+}
+
+#[cfg(any(test, feature = "testing"))]
+/// Rebuild a model by re-applying a recorded event log.
+///
+/// Only available with the `testing` feature or during tests.
+///
+/// Runs [`MvuLogic::init`] on `init_model`, then passes each event to
+/// [`MvuLogic::update`] in order, returning the final model. This reproduces the
+/// model of a session from its processed events, e.g. to debug a crash.
+///
+/// Effects returned by `init` and `update` are **not** executed, so the log must
+/// contain every processed event, including those that effects emitted.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{replay, Effect, Emitter, MvuLogic};
+///
+/// enum Event { Add(i32) }
+///
+/// struct Logic;
+///
+/// impl MvuLogic<Event, i32, ()> for Logic {
+///     fn init(&self, model: i32) -> (i32, Effect<Event>) { (model, Effect::none()) }
+///     fn update(&self, Event::Add(n): Event, model: &i32) -> (i32, Effect<Event>) {
+///         (model + n, Effect::none())
+///     }
+///     fn view(&self, _: &i32, _: &Emitter<Event>) {}
+/// }
+///
+/// assert_eq!(replay(&Logic, 0, vec![Event::Add(2), Event::Add(3)]), 5);
+/// ```
+pub fn replay<Event, Model, Props, Logic, Events>(
+    logic: &Logic,
+    init_model: Model,
+    events: Events,
+) -> Model
+where
+    Event: Send + 'static,
+    Logic: MvuLogic<Event, Model, Props>,
+    Events: IntoIterator<Item = Event>,
+{
+    let (model, _) = logic.init(init_model);
+
+    events
+        .into_iter()
+        .fold(model, |model, event| logic.update(event, &model).0)
+}
+
 #[cfg(any(test, feature = "testing"))]
 /// Test runtime driver for manual event processing control.
 ///
@@ -200,6 +1704,7 @@ where
     Spawn: Spawner,
 {
     _runtime: TestMvuRuntime<Event, Model, Props, Logic, Render, Spawn>,
+    init_effect_log: ExecutionLog,
 }
 
 #[cfg(any(test, feature = "testing"))]
@@ -217,9 +1722,161 @@ where
     ///
     /// This processes events until the queue is empty. Call this after emitting
     /// events to drive the event loop in tests.
+    ///
+    /// Effects are spawned as each event is processed, and with
+    /// [`create_test_spawner`] they run to completion immediately, so events
+    /// emitted by synchronous effects are queued and processed within the same
+    /// call. When this returns, events and their follow-ups have fully settled.
+    /// Use [`assert_at_fixed_point`](Self::assert_at_fixed_point) to guard
+    /// against logic that never settles.
     pub fn process_events(&mut self) {
         self._runtime.process_queued_events();
     }
+
+    /// Get a handle for interacting with the runtime.
+    pub fn handle(&self) -> RuntimeHandle<Event, Model> {
+        self._runtime.runtime.handle()
+    }
+
+    /// Number of times [`MvuLogic::view`] has been called for the primary view.
+    ///
+    /// Compare with the renderer's own render count to verify that view work was
+    /// skipped, e.g. by [`MvuLogic::view_key`].
+    pub fn view_count(&self) -> usize {
+        self._runtime.runtime.view_count
+    }
+
+    /// Number of model observers currently registered with the runtime.
+    ///
+    /// Observers registered through a [`RuntimeHandle`] are counted once the
+    /// event loop has picked them up, and stop being counted once they are
+    /// removed on a commit.
+    pub fn model_observer_count(&self) -> usize {
+        self._runtime.runtime.model_observers.len()
+    }
+
+    /// Labels of the initial effects, in the order they started executing.
+    ///
+    /// Each effect of a batch returned by [`MvuLogic::init`] is recorded with
+    /// its [`label`](Effect::label), or `None` if it has none, once its
+    /// execution starts. Effects still waiting for the spawner are not listed.
+    pub fn init_effect_execution_order(&self) -> Vec<Option<&'static str>> {
+        self.init_effect_log.lock().clone()
+    }
+
+    /// Duration of the most recent render.
+    ///
+    /// See [`MvuRuntime::last_render_duration`].
+    pub fn last_render_duration(&self) -> Option<Duration> {
+        self._runtime.runtime.last_render_duration()
+    }
+
+    /// Process queued events and assert that the runtime settles.
+    ///
+    /// Events are processed until the queue is empty, at which point no further
+    /// events are pending and the model can no longer change on its own.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the queue is still not empty after processing `max_iterations`
+    /// events, which usually indicates logic that keeps emitting events forever.
+    /// When a [`Coalescer`] is registered, each iteration processes one coalesced batch.
+    pub fn assert_at_fixed_point(&mut self, max_iterations: usize) {
+        let settled = self._runtime.process_queued_events_bounded(max_iterations);
+        assert!(
+            settled,
+            "runtime did not reach a fixed point after processing {} events",
+            max_iterations
+        );
+    }
+
+    /// Iterate over renders as queued events are processed.
+    ///
+    /// `renderer` must be the [`TestRenderer`] (or a clone of it) this runtime
+    /// renders to. See [`RenderStream`].
+    pub fn render_stream(
+        &mut self,
+        renderer: &TestRenderer<Props>,
+    ) -> RenderStream<'_, Event, Model, Props, Logic, Render, Spawn> {
+        RenderStream {
+            driver: self,
+            renderer: renderer.clone(),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+/// Iterator yielding the Props rendered as queued events are processed.
+///
+/// Only available with the `testing` feature or during tests.
+///
+/// Created by [`TestMvuDriver::render_stream`]. Each call to `next` processes
+/// queued events one at a time until one of them renders, then returns a clone
+/// of the rendered Props. Events that don't cause a render (e.g. because
+/// [`MvuLogic::view_key`] is unchanged) are processed without yielding.
+/// Iteration ends once the queue is empty.
+///
+/// # Example
+///
+/// ```rust
+/// use oxide_mvu::{create_test_spawner, Effect, Emitter, MvuLogic, TestMvuRuntime, TestRenderer};
+///
+/// # enum Event { Inc }
+/// # struct Counter;
+/// # impl MvuLogic<Event, i32, i32> for Counter {
+/// #     fn init(&self, m: i32) -> (i32, Effect<Event>) { (m, Effect::none()) }
+/// #     fn update(&self, _: Event, m: &i32) -> (i32, Effect<Event>) { (m + 1, Effect::none()) }
+/// #     fn view(&self, m: &i32, _: &Emitter<Event>) -> i32 { *m }
+/// # }
+/// let renderer = TestRenderer::new();
+/// let mut driver = TestMvuRuntime::new(0, Counter, renderer.clone(), create_test_spawner()).run();
+///
+/// driver.handle().emitter().emit(Event::Inc);
+/// driver.handle().emitter().emit(Event::Inc);
+///
+/// let renders: Vec<i32> = driver.render_stream(&renderer).collect();
+/// assert_eq!(renders, vec![1, 2]);
+/// ```
+pub struct RenderStream<'a, Event, Model, Props, Logic, Render, Spawn>
+where
+    Event: Send + 'static,
+    Model: Clone + 'static,
+    Props: 'static,
+    Logic: MvuLogic<Event, Model, Props>,
+    Render: Renderer<Props>,
+    Spawn: Spawner,
+{
+    driver: &'a mut TestMvuDriver<Event, Model, Props, Logic, Render, Spawn>,
+    renderer: TestRenderer<Props>,
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl<Event, Model, Props, Logic, Render, Spawn> Iterator
+    for RenderStream<'_, Event, Model, Props, Logic, Render, Spawn>
+where
+    Event: Send + 'static,
+    Model: Clone + 'static,
+    Props: Clone + 'static,
+    Logic: MvuLogic<Event, Model, Props>,
+    Render: Renderer<Props>,
+    Spawn: Spawner,
+{
+    type Item = Props;
+
+    fn next(&mut self) -> Option<Props> {
+        loop {
+            let rendered = self.renderer.count();
+            if !self.driver._runtime.process_next_event() {
+                return None;
+            }
+
+            if self.renderer.count() > rendered {
+                return self
+                    .renderer
+                    .with_renders(|renders| renders.last().cloned());
+            }
+        }
+    }
 }
 
 #[cfg(any(test, feature = "testing"))]
@@ -298,46 +1955,243 @@ where
     /// * `renderer` - Platform rendering implementation for rendering Props
     /// * `spawner` - Spawner to execute async effects on your chosen runtime
     pub fn new(init_model: Model, logic: Logic, renderer: Render, spawner: Spawn) -> Self {
-        // Create unbounded channel for event queue
-        let (event_sender, event_receiver) = flume::unbounded();
+        TestMvuRuntime {
+            runtime: MvuRuntime::new(init_model, logic, renderer, spawner),
+        }
+    }
+
+    /// Coalesce each batch of queued events before it is processed.
+    ///
+    /// See [`MvuRuntime::with_coalescer`].
+    pub fn with_coalescer<C>(self, coalescer: C) -> Self
+    where
+        C: Coalescer<Event> + Send + 'static,
+    {
+        TestMvuRuntime {
+            runtime: self.runtime.with_coalescer(coalescer),
+        }
+    }
+
+    /// Panic when an event is emitted while a view is being computed.
+    ///
+    /// See [`MvuRuntime::with_strict_view`].
+    pub fn with_strict_view(self) -> Self {
+        TestMvuRuntime {
+            runtime: self.runtime.with_strict_view(),
+        }
+    }
+
+    /// Report how many events each effect emitted.
+    ///
+    /// See [`MvuRuntime::with_effect_fanout`].
+    pub fn with_effect_fanout<F>(self, on_effect_fanout: F) -> Self
+    where
+        F: Fn(Option<&'static str>, usize) + Send + Sync + 'static,
+    {
+        TestMvuRuntime {
+            runtime: self.runtime.with_effect_fanout(on_effect_fanout),
+        }
+    }
 
+    /// Decide what happens when processing an event panics.
+    ///
+    /// See [`MvuRuntime::with_panic_policy`].
+    #[cfg(not(feature = "no_std"))]
+    pub fn with_panic_policy(self, policy: PanicPolicy) -> Self {
+        TestMvuRuntime {
+            runtime: self.runtime.with_panic_policy(policy),
+        }
+    }
+
+    /// Route failures while processing events through `boundary`.
+    ///
+    /// See [`MvuRuntime::with_error_boundary`].
+    #[cfg(not(feature = "no_std"))]
+    pub fn with_error_boundary<B>(self, boundary: B) -> Self
+    where
+        B: ErrorBoundary<Event, Model> + Send + 'static,
+    {
+        TestMvuRuntime {
+            runtime: self.runtime.with_error_boundary(boundary),
+        }
+    }
+
+    /// Choose whether the initial render happens before or after the initial effect.
+    ///
+    /// With [`InitRenderMode::AfterEffects`], [`run`](Self::run) processes the
+    /// events emitted by the initial effect before the first render. See
+    /// [`MvuRuntime::with_init_render_mode`].
+    pub fn with_init_render_mode(self, mode: InitRenderMode) -> Self {
+        TestMvuRuntime {
+            runtime: self.runtime.with_init_render_mode(mode),
+        }
+    }
+
+    /// Process queued events in batches with [`BatchMvuLogic::update_batch`].
+    ///
+    /// See [`MvuRuntime::with_batch_update`].
+    pub fn with_batch_update(self) -> Self
+    where
+        Logic: BatchMvuLogic<Event, Model, Props>,
+    {
+        TestMvuRuntime {
+            runtime: self.runtime.with_batch_update(),
+        }
+    }
+
+    /// Configure the event emitted by [`RuntimeHandle::tick`].
+    ///
+    /// See [`MvuRuntime::with_tick_event`].
+    pub fn with_tick_event<F>(self, tick_event: F) -> Self
+    where
+        F: Fn(Duration) -> Event + Send + 'static,
+    {
+        TestMvuRuntime {
+            runtime: self.runtime.with_tick_event(tick_event),
+        }
+    }
+
+    /// Detect events that keep re-triggering themselves.
+    ///
+    /// See [`MvuRuntime::with_emit_loop_detection`].
+    pub fn with_emit_loop_detection<F>(self, threshold: usize, on_emit_loop_detected: F) -> Self
+    where
+        Event: PartialEq + Clone,
+        F: FnMut(&Event, usize) + Send + 'static,
+    {
+        TestMvuRuntime {
+            runtime: self
+                .runtime
+                .with_emit_loop_detection(threshold, on_emit_loop_detected),
+        }
+    }
+
+    /// Transform Props after `view` and before they are rendered.
+    ///
+    /// See [`MvuRuntime::with_props_transform`].
+    pub fn with_props_transform<T>(self, transform: T) -> Self
+    where
+        T: PropsTransform<Props> + Send + 'static,
+    {
+        TestMvuRuntime {
+            runtime: self.runtime.with_props_transform(transform),
+        }
+    }
+
+    /// Measure how long each render takes.
+    ///
+    /// See [`MvuRuntime::with_render_timing`].
+    pub fn with_render_timing<C, F>(
+        self,
+        clock: C,
+        slow_render_threshold: Duration,
+        on_slow_render: F,
+    ) -> Self
+    where
+        C: Clock + Send + 'static,
+        F: FnMut(Duration) + Send + 'static,
+    {
+        TestMvuRuntime {
+            runtime: self
+                .runtime
+                .with_render_timing(clock, slow_render_threshold, on_slow_render),
+        }
+    }
+
+    /// Report which model fields changed before each render.
+    ///
+    /// See [`MvuRuntime::with_render_cause`].
+    pub fn with_render_cause<F>(self, on_render_cause: F) -> Self
+    where
+        F: FnMut(&[&'static str]) + Send + 'static,
+    {
+        TestMvuRuntime {
+            runtime: self.runtime.with_render_cause(on_render_cause),
+        }
+    }
+
+    /// Let the host decide when renders happen.
+    ///
+    /// See [`MvuRuntime::with_render_scheduler`].
+    pub fn with_render_scheduler<F>(self, schedule: F) -> Self
+    where
+        F: Fn(Box<dyn FnOnce() + Send>) + Send + 'static,
+    {
         TestMvuRuntime {
-            runtime: MvuRuntime {
-                logic,
-                renderer,
-                event_receiver,
-                model: init_model,
-                emitter: Emitter::new(event_sender),
-                spawner,
-                _props: core::marker::PhantomData,
-            },
+            runtime: self.runtime.with_render_scheduler(schedule),
         }
     }
 
+    /// Register an additional view rendered alongside the primary one.
+    ///
+    /// See [`MvuRuntime::add_view`].
+    pub fn add_view<OtherProps, F>(
+        &mut self,
+        project: F,
+        renderer: Box<dyn Renderer<OtherProps> + Send>,
+    ) where
+        OtherProps: 'static,
+        F: Fn(&Model, &Emitter<Event>) -> OtherProps + Send + 'static,
+    {
+        self.runtime.add_view(project, renderer);
+    }
+
     /// Initializes the runtime and returns a driver for manual event processing.
     ///
     /// This processes initial effects and renders the initial state, then returns
     /// a [`TestMvuDriver`] that provides manual control over event processing.
     pub fn run(mut self) -> TestMvuDriver<Event, Model, Props, Logic, Render, Spawn> {
-        let (init_model, init_effect) = self.runtime.logic.init(self.runtime.model.clone());
-
-        let initial_props = { self.runtime.logic.view(&init_model, &self.runtime.emitter) };
-
-        self.runtime.renderer.render(initial_props);
-
-        // Execute initial effect by spawning it
-        let future = init_effect.execute(&self.runtime.emitter);
-        self.runtime.spawner.spawn(Box::pin(future));
+        // Record the initial effects as they execute, for assertions on their order
+        let init_effect_log = ExecutionLog::default();
+        let init_effect = self.runtime.init().record_execution(&init_effect_log);
+        self.runtime.spawn_effect(init_effect);
+        if self.runtime.initial_render_pending {
+            self.process_queued_events();
+            self.runtime.finish_initial_render();
+        }
 
-        TestMvuDriver { _runtime: self }
+        TestMvuDriver {
+            _runtime: self,
+            init_effect_log,
+        }
     }
 
     /// Process all queued events (for testing).
     ///
     /// This is exposed for TestMvuRuntime to manually drive event processing.
     fn process_queued_events(&mut self) {
-        while let Ok(event) = self.runtime.event_receiver.try_recv() {
-            self.runtime.step(event);
+        self.runtime.start_drain();
+        while self.process_next_event() {}
+    }
+
+    /// Apply queued commands and process the next queued event.
+    ///
+    /// Returns whether there was an event to process.
+    fn process_next_event(&mut self) -> bool {
+        if self.runtime.shut_down {
+            return false;
+        }
+
+        self.runtime.apply_queued_commands();
+        match self.runtime.event_receiver.try_recv() {
+            Ok(event) => {
+                self.runtime.process_available(event);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Process queued events, giving up after `max_iterations` batches.
+    ///
+    /// Returns whether the queue was emptied.
+    fn process_queued_events_bounded(&mut self, max_iterations: usize) -> bool {
+        self.runtime.start_drain();
+        for _ in 0..max_iterations {
+            if !self.process_next_event() {
+                return true;
+            }
         }
+        self.runtime.event_receiver.is_empty()
     }
 }
@@ -0,0 +1,49 @@
+use oxide_mvu::{
+    block_on, create_test_spawner, Effect, Emitter, FnLogic, ResponseChannel, TestMvuRuntime,
+    TestRenderer,
+};
+
+#[derive(Clone)]
+enum Event {
+    Increment,
+    DoubledCountRequested(ResponseChannel<i32>),
+}
+
+#[derive(Clone)]
+struct Model {
+    count: i32,
+}
+
+#[test]
+fn given_an_ask_event_whose_handler_replies_should_resolve_with_the_reply() {
+    let logic = FnLogic::new(
+        |model: Model| (model, Effect::none()),
+        |event: Event, model: &Model| match event {
+            Event::Increment => (
+                Model {
+                    count: model.count + 1,
+                },
+                Effect::none(),
+            ),
+            Event::DoubledCountRequested(response) => {
+                response.reply(model.count * 2);
+                (model.clone(), Effect::none())
+            }
+        },
+        |model: &Model, _emitter: &Emitter<Event>| model.count,
+    );
+    let mut driver = TestMvuRuntime::new(
+        Model { count: 0 },
+        logic,
+        TestRenderer::new(),
+        create_test_spawner(),
+    )
+    .run();
+    let handle = driver.handle();
+
+    handle.emitter().emit(Event::Increment);
+    let doubled = handle.ask(Event::DoubledCountRequested);
+    driver.process_events();
+
+    assert_eq!(block_on(doubled), Some(2));
+}
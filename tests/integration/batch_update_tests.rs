@@ -0,0 +1,91 @@
+use oxide_mvu::{
+    create_test_spawner, BatchMvuLogic, Effect, Emitter, MvuLogic, TestMvuRuntime, TestRenderer,
+};
+
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Event {
+    Increment(i32),
+}
+
+#[derive(Clone)]
+struct Model {
+    count: i32,
+}
+
+struct Props {
+    count: i32,
+    on_increment: Box<dyn Fn(i32) + Send>,
+}
+
+struct CounterLogic {
+    batches: Arc<Mutex<Vec<Vec<Event>>>>,
+}
+
+impl MvuLogic<Event, Model, Props> for CounterLogic {
+    fn init(&self, model: Model) -> (Model, Effect<Event>) {
+        (model, Effect::none())
+    }
+
+    fn update(&self, event: Event, model: &Model) -> (Model, Effect<Event>) {
+        self.update_batch(vec![event], model)
+    }
+
+    fn view(&self, model: &Model, emitter: &Emitter<Event>) -> Props {
+        let emitter = emitter.clone();
+        Props {
+            count: model.count,
+            on_increment: Box::new(move |by| emitter.emit(Event::Increment(by))),
+        }
+    }
+}
+
+impl BatchMvuLogic<Event, Model, Props> for CounterLogic {
+    fn update_batch(&self, events: Vec<Event>, model: &Model) -> (Model, Effect<Event>) {
+        self.batches.lock().unwrap().push(events.clone());
+        let total: i32 = events.into_iter().map(|Event::Increment(by)| by).sum();
+        (
+            Model {
+                count: model.count + total,
+            },
+            Effect::none(),
+        )
+    }
+}
+
+#[test]
+fn given_batch_update_when_a_burst_is_queued_should_handle_it_in_a_single_update_in_order() {
+    let batches = Arc::new(Mutex::new(Vec::new()));
+    let renderer = TestRenderer::new();
+    let mut driver = TestMvuRuntime::new(
+        Model { count: 0 },
+        CounterLogic {
+            batches: batches.clone(),
+        },
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_batch_update()
+    .run();
+
+    renderer.with_renders(|renders| {
+        (renders[0].on_increment)(1);
+        (renders[0].on_increment)(2);
+        (renders[0].on_increment)(3);
+    });
+    driver.process_events();
+
+    assert_eq!(
+        *batches.lock().unwrap(),
+        vec![vec![
+            Event::Increment(1),
+            Event::Increment(2),
+            Event::Increment(3)
+        ]]
+    );
+    renderer.with_renders(|renders| {
+        assert_eq!(renders.len(), 2);
+        assert_eq!(renders[1].count, 6);
+    });
+}
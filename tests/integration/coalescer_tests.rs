@@ -0,0 +1,89 @@
+use oxide_mvu::{
+    create_test_spawner, Coalescer, Effect, Emitter, MvuLogic, TestMvuRuntime, TestRenderer,
+};
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[derive(Clone)]
+enum Event {
+    ScrollBy(i32),
+}
+
+#[derive(Clone)]
+struct Model {
+    offset: i32,
+}
+
+struct Props {
+    offset: i32,
+    on_scroll: Box<dyn Fn(i32) + Send>,
+}
+
+struct ScrollLogic {
+    update_calls: Arc<AtomicUsize>,
+}
+
+impl MvuLogic<Event, Model, Props> for ScrollLogic {
+    fn init(&self, model: Model) -> (Model, Effect<Event>) {
+        (model, Effect::none())
+    }
+
+    fn update(&self, event: Event, model: &Model) -> (Model, Effect<Event>) {
+        self.update_calls.fetch_add(1, Ordering::SeqCst);
+        match event {
+            Event::ScrollBy(delta) => (
+                Model {
+                    offset: model.offset + delta,
+                },
+                Effect::none(),
+            ),
+        }
+    }
+
+    fn view(&self, model: &Model, emitter: &Emitter<Event>) -> Props {
+        let emitter = emitter.clone();
+        Props {
+            offset: model.offset,
+            on_scroll: Box::new(move |delta| emitter.emit(Event::ScrollBy(delta))),
+        }
+    }
+}
+
+struct SumScrolls;
+
+impl Coalescer<Event> for SumScrolls {
+    fn coalesce(&self, pending: &mut Vec<Event>) {
+        let total = pending.drain(..).map(|Event::ScrollBy(delta)| delta).sum();
+        pending.push(Event::ScrollBy(total));
+    }
+}
+
+#[test]
+fn given_a_summing_coalescer_when_several_scrolls_are_queued_should_update_once_with_the_total() {
+    let update_calls = Arc::new(AtomicUsize::new(0));
+    let renderer = TestRenderer::new();
+    let mut driver = TestMvuRuntime::new(
+        Model { offset: 0 },
+        ScrollLogic {
+            update_calls: update_calls.clone(),
+        },
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_coalescer(SumScrolls)
+    .run();
+
+    renderer.with_renders(|renders| {
+        (renders[0].on_scroll)(1);
+        (renders[0].on_scroll)(2);
+        (renders[0].on_scroll)(3);
+    });
+    driver.process_events();
+
+    assert_eq!(update_calls.load(Ordering::SeqCst), 1);
+    assert_eq!(renderer.count(), 2);
+    renderer.with_renders(|renders| {
+        assert_eq!(renders[1].offset, 6);
+    });
+}
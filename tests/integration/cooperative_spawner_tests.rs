@@ -0,0 +1,52 @@
+use super::{build_test_logic, TestEvent, TestModel};
+use oxide_mvu::{CooperativeSpawner, Effect, MvuRuntime, TestRenderer};
+
+use futures::task::noop_waker;
+use std::task::{Context, Poll};
+
+#[test]
+fn given_a_cooperative_spawner_should_drive_async_effects_on_the_event_loop() {
+    let renderer = TestRenderer::new();
+    let spawner = CooperativeSpawner::new();
+    let initial_effect = Effect::from_async(|emitter| async move {
+        futures::future::ready(()).await;
+        emitter.emit(TestEvent::Increment);
+    });
+    let mut runtime = MvuRuntime::new(
+        TestModel { count: 0 },
+        build_test_logic(initial_effect),
+        renderer.clone(),
+        spawner.clone(),
+    );
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    assert_eq!(runtime.poll_once(&mut cx), Poll::Pending);
+
+    assert_eq!(spawner.pending_tasks(), 0);
+    assert_eq!(renderer.count(), 2);
+    renderer.with_renders(|renders| {
+        assert_eq!(renders[1].count, 1);
+    });
+}
+
+#[test]
+fn given_a_cooperative_runtime_without_a_spawner_should_run_async_effects() {
+    let renderer = TestRenderer::new();
+    let initial_effect = Effect::from_async(|emitter| async move {
+        emitter.emit(TestEvent::Set(3));
+    });
+    let mut runtime = MvuRuntime::new_cooperative(
+        TestModel { count: 0 },
+        build_test_logic(initial_effect),
+        renderer.clone(),
+    );
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    assert_eq!(runtime.poll_once(&mut cx), Poll::Pending);
+
+    renderer.with_renders(|renders| {
+        assert_eq!(renders.last().unwrap().count, 3);
+    });
+}
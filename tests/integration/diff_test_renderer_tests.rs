@@ -0,0 +1,49 @@
+use super::{build_test_logic, TestModel, TestProps};
+use oxide_mvu::{create_test_spawner, DiffTestRenderer, Effect, TestMvuRuntime};
+
+#[derive(Clone, Debug, PartialEq)]
+enum FieldChange {
+    Count { from: i32, to: i32 },
+}
+
+fn diff_props(previous: &TestProps, next: &TestProps) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    if previous.count != next.count {
+        changes.push(FieldChange::Count {
+            from: previous.count,
+            to: next.count,
+        });
+    }
+    changes
+}
+
+#[test]
+fn given_successive_models_should_record_only_the_count_changes_after_the_first_render() {
+    let renderer = DiffTestRenderer::new(diff_props);
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        build_test_logic(Effect::none()),
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .run();
+
+    assert!(renderer.changes().is_empty());
+
+    let handle = driver.handle();
+    handle.set_model(TestModel { count: 1 });
+    driver.process_events();
+    handle.set_model(TestModel { count: 1 });
+    driver.process_events();
+    handle.set_model(TestModel { count: 2 });
+    driver.process_events();
+
+    assert_eq!(
+        renderer.changes(),
+        vec![
+            vec![FieldChange::Count { from: 0, to: 1 }],
+            vec![],
+            vec![FieldChange::Count { from: 1, to: 2 }],
+        ]
+    );
+}
@@ -0,0 +1,175 @@
+use oxide_mvu::{
+    create_test_spawner, DispatchLogic, Effect, Emitter, FnLogic, Lens, TestMvuRuntime,
+    TestRenderer,
+};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct AppModel {
+    count: i32,
+    enabled: bool,
+    resets: u32,
+}
+
+enum CounterEvent {
+    Add(i32),
+}
+
+enum ToggleEvent {
+    Flip,
+}
+
+enum AppEvent {
+    Counter(CounterEvent),
+    Toggle(ToggleEvent),
+    Reset,
+}
+
+fn counter_update(event: AppEvent, model: &AppModel) -> (AppModel, Effect<AppEvent>) {
+    match event {
+        AppEvent::Counter(CounterEvent::Add(amount)) => (
+            AppModel {
+                count: model.count + amount,
+                ..model.clone()
+            },
+            Effect::none(),
+        ),
+        _ => unreachable!("routed a non-counter event to the counter module"),
+    }
+}
+
+fn toggle_update(event: AppEvent, model: &AppModel) -> (AppModel, Effect<AppEvent>) {
+    match event {
+        AppEvent::Toggle(ToggleEvent::Flip) => (
+            AppModel {
+                enabled: !model.enabled,
+                ..model.clone()
+            },
+            Effect::none(),
+        ),
+        _ => unreachable!("routed a non-toggle event to the toggle module"),
+    }
+}
+
+#[test]
+fn given_feature_modules_should_dispatch_each_event_to_its_own_module() {
+    let renderer = TestRenderer::new();
+    let base = FnLogic::new(
+        |model: AppModel| (model, Effect::none()),
+        |_event: AppEvent, model: &AppModel| {
+            (
+                AppModel {
+                    resets: model.resets + 1,
+                    ..AppModel::default()
+                },
+                Effect::none(),
+            )
+        },
+        |model: &AppModel, _emitter: &Emitter<AppEvent>| model.clone(),
+    );
+    let logic = DispatchLogic::new(base)
+        .route(
+            |event| matches!(event, AppEvent::Counter(_)),
+            counter_update,
+        )
+        .route(|event| matches!(event, AppEvent::Toggle(_)), toggle_update);
+    let mut driver = TestMvuRuntime::new(
+        AppModel::default(),
+        logic,
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .run();
+
+    let emitter = driver.handle().emitter();
+    emitter.emit(AppEvent::Counter(CounterEvent::Add(2)));
+    emitter.emit(AppEvent::Toggle(ToggleEvent::Flip));
+    emitter.emit(AppEvent::Counter(CounterEvent::Add(3)));
+    driver.process_events();
+
+    renderer.with_renders(|renders| {
+        assert_eq!(
+            renders.last().unwrap(),
+            &AppModel {
+                count: 5,
+                enabled: true,
+                resets: 0,
+            }
+        );
+    });
+
+    emitter.emit(AppEvent::Reset);
+    driver.process_events();
+
+    renderer.with_renders(|renders| {
+        assert_eq!(
+            renders.last().unwrap(),
+            &AppModel {
+                count: 0,
+                enabled: false,
+                resets: 1,
+            }
+        );
+    });
+}
+
+#[test]
+fn given_feature_modules_focused_by_lenses_should_update_only_their_own_sub_model() {
+    let renderer = TestRenderer::new();
+    let base = FnLogic::new(
+        |model: AppModel| (model, Effect::none()),
+        |_event: AppEvent, model: &AppModel| (model.clone(), Effect::none()),
+        |model: &AppModel, _emitter: &Emitter<AppEvent>| model.clone(),
+    );
+    let count = Lens::new(
+        |model: &AppModel| &model.count,
+        |model, count| model.count = count,
+    );
+    let enabled = Lens::new(
+        |model: &AppModel| &model.enabled,
+        |model, enabled| model.enabled = enabled,
+    );
+    let logic = DispatchLogic::new(base)
+        .route_lens(
+            |event| matches!(event, AppEvent::Counter(_)),
+            count,
+            |event, count: &i32| match event {
+                AppEvent::Counter(CounterEvent::Add(amount)) => (count + amount, Effect::none()),
+                _ => unreachable!("routed a non-counter event to the counter module"),
+            },
+        )
+        .route_lens(
+            |event| matches!(event, AppEvent::Toggle(_)),
+            enabled,
+            |event, enabled: &bool| match event {
+                AppEvent::Toggle(ToggleEvent::Flip) => (!enabled, Effect::none()),
+                _ => unreachable!("routed a non-toggle event to the toggle module"),
+            },
+        );
+    let mut driver = TestMvuRuntime::new(
+        AppModel {
+            resets: 7,
+            ..AppModel::default()
+        },
+        logic,
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .run();
+
+    let emitter = driver.handle().emitter();
+    emitter.emit(AppEvent::Counter(CounterEvent::Add(2)));
+    emitter.emit(AppEvent::Toggle(ToggleEvent::Flip));
+    emitter.emit(AppEvent::Counter(CounterEvent::Add(3)));
+    driver.process_events();
+
+    renderer.with_renders(|renders| {
+        assert_eq!(
+            renders.last().unwrap(),
+            &AppModel {
+                count: 5,
+                enabled: true,
+                resets: 7,
+            }
+        );
+    });
+}
@@ -0,0 +1,37 @@
+use super::{build_test_logic, MockEffectsDependency, TestEvent, TestLogic, TestModel};
+use oxide_mvu::{dry_update, Effect, EffectDescription};
+
+#[test]
+fn given_an_increment_with_no_side_effect_should_describe_the_new_model_and_no_effect() {
+    let logic = build_test_logic(Effect::none());
+
+    let (model, effect) = dry_update(&logic, TestEvent::Increment, &TestModel { count: 1 });
+
+    assert_eq!(model, TestModel { count: 2 });
+    assert_eq!(effect, EffectDescription::None);
+}
+
+#[test]
+fn given_a_side_effect_with_async_work_should_describe_its_shape_without_executing_it() {
+    let mut effects = MockEffectsDependency::new();
+    effects.expect_on_increment_side_effect().returning(|| {
+        Effect::batch(vec![
+            Effect::just(TestEvent::Set(0)),
+            Effect::from_async(|_emitter| async { panic!("effect must not run") }),
+        ])
+    });
+    let logic = TestLogic {
+        effects: Box::new(effects),
+        ..build_test_logic(Effect::none())
+    };
+
+    let (_, effect) = dry_update(&logic, TestEvent::Increment, &TestModel { count: 0 });
+
+    assert_eq!(
+        effect,
+        EffectDescription::Batch(vec![
+            EffectDescription::Just(TestEvent::Set(0)),
+            EffectDescription::Async,
+        ])
+    );
+}
@@ -1,5 +1,13 @@
-use super::{build_integration_test, TestEvent};
-use oxide_mvu::Effect;
+use super::{build_integration_test, build_test_logic, TestEvent, TestModel};
+use oxide_mvu::{
+    block_on, create_test_spawner, inspect_init, Effect, EffectScope, Emitter, FnLogic,
+    ModelAccess, MvuRuntime, Persistence, TestMvuRuntime, TestRenderer,
+};
+
+use futures::task::noop_waker;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::task::{Context, Poll};
 
 #[test]
 fn given_no_initial_event_should_render_initial_props() {
@@ -68,3 +76,580 @@ fn given_a_batch_of_increment_effects_as_initial_effect_should_incrementally_upd
         assert_eq!(renders[3].count, 3);
     });
 }
+
+#[test]
+fn given_an_async_effect_with_a_completion_event_should_emit_the_completion_event_after_the_effect_events(
+) {
+    let test = build_integration_test()
+        .given_an_initial_effect(
+            Effect::from_async(|emitter| async move {
+                emitter.emit(TestEvent::Increment);
+                emitter.emit(TestEvent::Increment);
+            })
+            .on_complete(TestEvent::Set(10)),
+        )
+        .build();
+
+    assert_eq!(
+        test.driver.handle().take_pending(),
+        vec![
+            TestEvent::Increment,
+            TestEvent::Increment,
+            TestEvent::Set(10)
+        ]
+    );
+}
+
+#[test]
+fn given_an_initial_batch_effect_when_inspecting_init_should_return_the_model_and_bootstrap_events()
+{
+    let logic = build_test_logic(Effect::batch(vec![
+        Effect::just(TestEvent::Increment),
+        Effect::just(TestEvent::Set(5)),
+    ]));
+
+    let (model, events) = inspect_init(&logic, TestModel { count: 3 });
+
+    assert_eq!(model, TestModel { count: 3 });
+    assert_eq!(events, vec![TestEvent::Increment, TestEvent::Set(5)]);
+}
+
+#[test]
+fn given_an_effect_from_no_option_should_emit_nothing() {
+    let logic = build_test_logic(Effect::from_option(None));
+
+    let (_, events) = inspect_init(&logic, TestModel { count: 0 });
+
+    assert!(events.is_empty());
+}
+
+#[test]
+fn given_an_effect_from_some_option_should_emit_the_event() {
+    let logic = build_test_logic(Effect::from_option(Some(TestEvent::Set(3))));
+
+    let (_, events) = inspect_init(&logic, TestModel { count: 0 });
+
+    assert_eq!(events, vec![TestEvent::Set(3)]);
+}
+
+#[test]
+fn given_an_effect_from_an_ok_result_event_should_emit_the_ok_event() {
+    let logic = build_test_logic(Effect::from_result_event(Ok(TestEvent::Increment)));
+
+    let (_, events) = inspect_init(&logic, TestModel { count: 0 });
+
+    assert_eq!(events, vec![TestEvent::Increment]);
+}
+
+#[test]
+fn given_an_effect_from_an_err_result_event_should_emit_the_err_event() {
+    let logic = build_test_logic(Effect::from_result_event(Err(TestEvent::Set(-1))));
+
+    let (_, events) = inspect_init(&logic, TestModel { count: 0 });
+
+    assert_eq!(events, vec![TestEvent::Set(-1)]);
+}
+
+#[test]
+fn given_an_immediate_async_effect_when_driven_by_block_on_should_emit_its_event() {
+    let logic = build_test_logic(Effect::from_async(|emitter| async move {
+        let value = async { 7 }.await;
+        emitter.emit(TestEvent::Set(value));
+    }));
+
+    let (_, events) = inspect_init(&logic, TestModel { count: 0 });
+
+    assert_eq!(events, vec![TestEvent::Set(7)]);
+    assert_eq!(block_on(async { 21 * 2 }), 42);
+}
+
+#[test]
+fn given_a_guarded_effect_whose_condition_is_false_should_emit_nothing() {
+    let relevant = Arc::new(AtomicBool::new(true));
+    let condition = relevant.clone();
+    let logic = build_test_logic(Effect::guard(
+        move || condition.load(Ordering::SeqCst),
+        Effect::just(TestEvent::Increment),
+    ));
+
+    // Flipped after the effect was created, but before it executes
+    relevant.store(false, Ordering::SeqCst);
+    let (_, events) = inspect_init(&logic, TestModel { count: 0 });
+
+    assert!(events.is_empty());
+}
+
+#[test]
+fn given_a_guarded_effect_whose_condition_is_true_should_run_the_inner_effect() {
+    let logic = build_test_logic(Effect::guard(|| true, Effect::just(TestEvent::Increment)));
+
+    let (_, events) = inspect_init(&logic, TestModel { count: 0 });
+
+    assert_eq!(events, vec![TestEvent::Increment]);
+}
+
+#[test]
+fn given_a_finally_event_when_the_effect_succeeds_should_emit_it_after_the_effect_events() {
+    let logic = build_test_logic(
+        Effect::from_async(|emitter| async move {
+            emitter.emit(TestEvent::Increment);
+        })
+        .finally(TestEvent::Set(0)),
+    );
+
+    let (_, events) = inspect_init(&logic, TestModel { count: 0 });
+
+    assert_eq!(events, vec![TestEvent::Increment, TestEvent::Set(0)]);
+}
+
+#[test]
+fn given_a_finally_event_when_the_effect_fails_should_emit_it_after_the_failure_event() {
+    let logic = build_test_logic(
+        Effect::from_async(|emitter| async move {
+            let result: Result<i32, i32> = async { Err(-1) }.await;
+            match result {
+                Ok(value) => emitter.emit(TestEvent::Set(value)),
+                Err(code) => emitter.emit(TestEvent::Set(code)),
+            }
+        })
+        .finally(TestEvent::Set(0)),
+    );
+
+    let (_, events) = inspect_init(&logic, TestModel { count: 0 });
+
+    assert_eq!(events, vec![TestEvent::Set(-1), TestEvent::Set(0)]);
+}
+
+#[test]
+fn given_a_finally_event_when_the_effect_is_cancelled_should_still_emit_it() {
+    let test = build_integration_test().given_no_initial_event().build();
+    let handle = test.driver.handle();
+    let effect = Effect::from_async(|emitter| async move {
+        futures::future::pending::<()>().await;
+        emitter.emit(TestEvent::Increment);
+    })
+    .finally(TestEvent::Set(0));
+
+    let mut future = effect.execute(&handle.emitter());
+    let waker = noop_waker();
+    assert!(future
+        .as_mut()
+        .poll(&mut Context::from_waker(&waker))
+        .is_pending());
+    drop(future);
+
+    assert_eq!(handle.take_pending(), vec![TestEvent::Set(0)]);
+}
+
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn given_a_finally_event_when_the_effect_panics_should_still_emit_it() {
+    let test = build_integration_test().given_no_initial_event().build();
+    let handle = test.driver.handle();
+    let effect = Effect::from_async(|_emitter| async move {
+        panic!("effect failure");
+    })
+    .finally(TestEvent::Set(0));
+
+    let future = effect.execute(&handle.emitter());
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| block_on(future)));
+
+    assert!(result.is_err());
+    assert_eq!(handle.take_pending(), vec![TestEvent::Set(0)]);
+}
+
+#[test]
+fn given_a_selected_effect_when_the_model_changes_before_execution_should_use_the_execution_time_model(
+) {
+    let mut test = build_integration_test().given_no_initial_event().build();
+    let handle = test.driver.handle();
+    let access = ModelAccess::new(&handle);
+    test.driver.process_events();
+
+    let effect = Effect::select(&access, |model: &TestModel| {
+        if model.count > 5 {
+            Effect::just(TestEvent::Set(100))
+        } else {
+            Effect::just(TestEvent::Set(0))
+        }
+    });
+
+    handle.set_model(TestModel { count: 10 });
+    test.driver.process_events();
+    block_on(effect.execute(&handle.emitter()));
+
+    assert_eq!(handle.take_pending(), vec![TestEvent::Set(100)]);
+}
+
+#[test]
+fn given_a_from_model_effect_should_emit_only_when_the_model_exceeds_the_threshold() {
+    let mut test = build_integration_test().given_no_initial_event().build();
+    let handle = test.driver.handle();
+    let access = ModelAccess::new(&handle);
+    test.driver.process_events();
+    let over_threshold = || {
+        Effect::from_model(&access, |model: &TestModel| {
+            (model.count > 5).then_some(TestEvent::Set(-1))
+        })
+    };
+
+    block_on(over_threshold().execute(&handle.emitter()));
+    assert_eq!(handle.take_pending(), vec![]);
+
+    handle.set_model(TestModel { count: 10 });
+    test.driver.process_events();
+    block_on(over_threshold().execute(&handle.emitter()));
+    assert_eq!(handle.take_pending(), vec![TestEvent::Set(-1)]);
+}
+
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn given_a_blocking_effect_should_emit_the_value_computed_on_its_thread() {
+    let logic = build_test_logic(Effect::from_blocking(|| {
+        let product = (1..=5).product::<i32>();
+        TestEvent::Set(product)
+    }));
+
+    let (_, events) = inspect_init(&logic, TestModel { count: 0 });
+
+    assert_eq!(events, vec![TestEvent::Set(120)]);
+}
+
+#[test]
+fn given_two_child_effects_when_merged_should_emit_both_children_events_lifted_in_order() {
+    enum CounterEvent {
+        Bumped,
+    }
+    enum ResetEvent {
+        ResetTo(i32),
+    }
+
+    let test = build_integration_test().given_no_initial_event().build();
+    let handle = test.driver.handle();
+    let counter_effect = Effect::batch(vec![
+        Effect::just(CounterEvent::Bumped),
+        Effect::from_async(|emitter| async move { emitter.emit(CounterEvent::Bumped) }),
+    ]);
+    let reset_effect = Effect::just(ResetEvent::ResetTo(4));
+
+    let effect = Effect::merge2(
+        counter_effect,
+        |CounterEvent::Bumped| TestEvent::Increment,
+        reset_effect,
+        |ResetEvent::ResetTo(count)| TestEvent::Set(count),
+    );
+    block_on(effect.execute(&handle.emitter()));
+
+    assert_eq!(
+        handle.take_pending(),
+        vec![
+            TestEvent::Increment,
+            TestEvent::Increment,
+            TestEvent::Set(4)
+        ]
+    );
+}
+
+#[test]
+fn given_a_persist_effect_should_save_the_model_current_at_execution() {
+    struct InMemoryPersistence(Arc<Mutex<Vec<TestModel>>>);
+
+    impl Persistence<TestModel> for InMemoryPersistence {
+        fn save(&self, model: &TestModel) {
+            self.0.lock().unwrap().push(model.clone());
+        }
+    }
+
+    let mut test = build_integration_test().given_no_initial_event().build();
+    let handle = test.driver.handle();
+    let access = ModelAccess::new(&handle);
+    let saved = Arc::new(Mutex::new(Vec::new()));
+    let effect = Effect::persist(&access, InMemoryPersistence(saved.clone()));
+
+    handle.set_model(TestModel { count: 8 });
+    test.driver.process_events();
+    block_on(effect.execute(&handle.emitter()));
+
+    assert_eq!(*saved.lock().unwrap(), vec![TestModel { count: 8 }]);
+    assert_eq!(handle.take_pending(), vec![]);
+}
+
+#[test]
+fn given_labeled_initial_effects_should_execute_them_in_declared_order() {
+    let test = build_integration_test()
+        .given_an_initial_effect(Effect::batch(vec![
+            Effect::just(TestEvent::Set(3)).labeled("restore"),
+            Effect::just(TestEvent::Increment).labeled("warm_up"),
+            Effect::just(TestEvent::Increment),
+        ]))
+        .build();
+
+    assert_eq!(
+        test.driver.init_effect_execution_order(),
+        vec![Some("restore"), Some("warm_up"), None]
+    );
+}
+
+#[test]
+fn given_a_mapped_batch_of_labeled_effects_should_keep_each_effect_and_label() {
+    enum ChildEvent {
+        Loaded(i32),
+    }
+
+    let renderer = TestRenderer::new();
+    let logic = FnLogic::new(
+        |model: TestModel| {
+            let child_effect = Effect::batch(vec![
+                Effect::just(ChildEvent::Loaded(1)).labeled("cache"),
+                Effect::from_async(|emitter| async move { emitter.emit(ChildEvent::Loaded(2)) })
+                    .labeled("network"),
+            ]);
+            (
+                model,
+                child_effect.map(|ChildEvent::Loaded(count)| TestEvent::Set(count)),
+            )
+        },
+        |event: TestEvent, model: &TestModel| match event {
+            TestEvent::Increment => (
+                TestModel {
+                    count: model.count + 1,
+                },
+                Effect::none(),
+            ),
+            TestEvent::Set(count) => (TestModel { count }, Effect::none()),
+        },
+        |model: &TestModel, _emitter: &Emitter<TestEvent>| model.count,
+    );
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        logic,
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .run();
+    driver.process_events();
+
+    assert_eq!(
+        driver.init_effect_execution_order(),
+        vec![Some("cache"), Some("network")]
+    );
+    renderer.with_renders(|renders| {
+        assert_eq!(renders, &vec![0, 1, 2]);
+    });
+}
+
+#[test]
+fn given_a_once_fn_effect_should_run_the_closure_with_its_moved_resource_once() {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let counter = runs.clone();
+    let resource = String::from("four");
+    let logic = build_test_logic(Effect::once_fn(move |emitter| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        emitter.emit(TestEvent::Set(resource.len() as i32));
+    }));
+
+    let (_, events) = inspect_init(&logic, TestModel { count: 0 });
+
+    assert_eq!(events, vec![TestEvent::Set(4)]);
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn given_scoped_looping_effects_when_the_scope_is_cancelled_should_stop_them_without_emitting() {
+    let test = build_integration_test().given_no_initial_event().build();
+    let handle = test.driver.handle();
+    let scope = EffectScope::new();
+    let released = Arc::new(AtomicBool::new(false));
+
+    let workers: Vec<_> = (0..3)
+        .map(|_| {
+            let released = released.clone();
+            let effect = Effect::scoped(
+                &scope,
+                Effect::from_async(|emitter| async move {
+                    loop {
+                        if released.load(Ordering::SeqCst) {
+                            emitter.emit(TestEvent::Increment);
+                        }
+                        futures::pending!();
+                    }
+                }),
+            );
+            let future = effect.execute(&handle.emitter());
+            std::thread::spawn(move || block_on(future))
+        })
+        .collect();
+
+    scope.cancel_all();
+    released.store(true, Ordering::SeqCst);
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    assert!(handle.take_pending().is_empty());
+}
+
+#[test]
+fn given_two_effects_added_together_should_emit_them_in_order() {
+    let logic =
+        build_test_logic(Effect::just(TestEvent::Increment) + Effect::just(TestEvent::Set(5)));
+
+    let (_, events) = inspect_init(&logic, TestModel { count: 0 });
+
+    assert_eq!(events, vec![TestEvent::Increment, TestEvent::Set(5)]);
+}
+
+type Task = std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>>;
+
+#[test]
+fn given_a_versioned_effect_when_the_model_is_replaced_before_it_emits_should_suppress_its_event() {
+    let access: Arc<OnceLock<ModelAccess<TestModel>>> = Arc::new(OnceLock::new());
+    let tasks: Arc<Mutex<Vec<Task>>> = Arc::default();
+    let renderer = TestRenderer::new();
+    let logic = FnLogic::new(
+        |model: TestModel| (model, Effect::none()),
+        {
+            let access = access.clone();
+            move |event: TestEvent, model: &TestModel| match event {
+                TestEvent::Increment => (
+                    TestModel {
+                        count: model.count + 1,
+                    },
+                    // Result of slow optimistic work based on the incremented model
+                    Effect::if_version(access.get().unwrap(), Effect::just(TestEvent::Set(100))),
+                ),
+                TestEvent::Set(count) => (TestModel { count }, Effect::none()),
+            }
+        },
+        |model: &TestModel, _emitter: &Emitter<TestEvent>| model.count,
+    );
+    let spawner = {
+        let tasks = tasks.clone();
+        move |task: Task| tasks.lock().unwrap().push(task)
+    };
+    let mut driver =
+        TestMvuRuntime::new(TestModel { count: 0 }, logic, renderer.clone(), spawner).run();
+    let handle = driver.handle();
+    let _ = access.set(ModelAccess::new(&handle));
+    let run_tasks = || {
+        let pending: Vec<Task> = tasks.lock().unwrap().drain(..).collect();
+        pending.into_iter().for_each(block_on);
+    };
+
+    handle.emitter().emit(TestEvent::Increment);
+    driver.process_events();
+    run_tasks();
+    driver.process_events();
+
+    handle.emitter().emit(TestEvent::Increment);
+    driver.process_events();
+    handle.set_model(TestModel { count: 5 });
+    driver.process_events();
+    run_tasks();
+    driver.process_events();
+
+    renderer.with_renders(|renders| {
+        assert_eq!(renders, &vec![0, 1, 100, 101, 5]);
+    });
+}
+
+#[test]
+fn given_a_versioned_effect_that_emits_twice_should_deliver_its_result_after_its_progress() {
+    let access: Arc<OnceLock<ModelAccess<TestModel>>> = Arc::new(OnceLock::new());
+    let finished = Arc::new(AtomicBool::new(false));
+    let tasks: Arc<Mutex<Vec<Task>>> = Arc::default();
+    let renderer = TestRenderer::new();
+    let logic = FnLogic::new(
+        |model: TestModel| (model, Effect::none()),
+        {
+            let access = access.clone();
+            let finished = finished.clone();
+            move |event: TestEvent, model: &TestModel| match event {
+                TestEvent::Increment => {
+                    let finished = finished.clone();
+                    let work = Effect::from_async(move |emitter| async move {
+                        // Report progress, then the result once the work finishes
+                        emitter.emit(TestEvent::Set(50));
+                        std::future::poll_fn(|_| match finished.load(Ordering::Acquire) {
+                            true => Poll::Ready(()),
+                            false => Poll::Pending,
+                        })
+                        .await;
+                        emitter.emit(TestEvent::Set(100));
+                    });
+                    (
+                        TestModel {
+                            count: model.count + 1,
+                        },
+                        Effect::if_version(access.get().unwrap(), work),
+                    )
+                }
+                TestEvent::Set(count) => (TestModel { count }, Effect::none()),
+            }
+        },
+        |model: &TestModel, _emitter: &Emitter<TestEvent>| model.count,
+    );
+    let spawner = {
+        let tasks = tasks.clone();
+        move |task: Task| tasks.lock().unwrap().push(task)
+    };
+    let mut driver =
+        TestMvuRuntime::new(TestModel { count: 0 }, logic, renderer.clone(), spawner).run();
+    let handle = driver.handle();
+    let _ = access.set(ModelAccess::new(&handle));
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    handle.emitter().emit(TestEvent::Increment);
+    driver.process_events();
+    let mut task = tasks.lock().unwrap().pop().unwrap();
+    assert!(task.as_mut().poll(&mut cx).is_pending());
+    driver.process_events();
+    finished.store(true, Ordering::Release);
+    assert!(task.as_mut().poll(&mut cx).is_ready());
+    driver.process_events();
+
+    renderer.with_renders(|renders| {
+        assert_eq!(renders, &vec![0, 1, 50, 100]);
+    });
+}
+
+#[test]
+fn given_a_versioned_init_effect_should_deliver_its_event_unchecked() {
+    let access: Arc<OnceLock<ModelAccess<TestModel>>> = Arc::new(OnceLock::new());
+    let renderer = TestRenderer::new();
+    let logic = FnLogic::new(
+        {
+            let access = access.clone();
+            move |model: TestModel| {
+                let effect = Effect::just(TestEvent::Set(7));
+                (model, Effect::if_version(access.get().unwrap(), effect))
+            }
+        },
+        |event: TestEvent, model: &TestModel| match event {
+            TestEvent::Increment => (
+                TestModel {
+                    count: model.count + 1,
+                },
+                Effect::none(),
+            ),
+            TestEvent::Set(count) => (TestModel { count }, Effect::none()),
+        },
+        |model: &TestModel, _emitter: &Emitter<TestEvent>| model.count,
+    );
+    let mut runtime = MvuRuntime::new(
+        TestModel { count: 0 },
+        logic,
+        renderer.clone(),
+        create_test_spawner(),
+    );
+    let _ = access.set(ModelAccess::new(&runtime.handle()));
+    let waker = noop_waker();
+
+    let _ = runtime.poll_once(&mut Context::from_waker(&waker));
+
+    assert_eq!(access.get().unwrap().read(|model| model.count), Some(7));
+    renderer.with_renders(|renders| {
+        assert_eq!(renders.last(), Some(&7));
+    });
+}
@@ -0,0 +1,31 @@
+use super::{build_test_logic, TestEvent, TestModel};
+use oxide_mvu::{create_test_spawner, Effect, TestMvuRuntime, TestRenderer};
+
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn given_a_labeled_batch_of_five_events_should_report_a_fanout_of_five_for_its_label() {
+    let initial_effect = Effect::batch(vec![
+        Effect::just(TestEvent::Increment),
+        Effect::just(TestEvent::Increment),
+        Effect::just(TestEvent::Increment),
+        Effect::just(TestEvent::Increment),
+        Effect::just(TestEvent::Increment),
+    ])
+    .labeled("burst");
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let recorded = reports.clone();
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        build_test_logic(initial_effect),
+        TestRenderer::new(),
+        create_test_spawner(),
+    )
+    .with_effect_fanout(move |label, count| recorded.lock().unwrap().push((label, count)))
+    .run();
+    driver.process_events();
+
+    let reports = reports.lock().unwrap();
+    assert_eq!(reports[0], (Some("burst"), 5));
+    assert!(reports[1..].iter().all(|report| *report == (None, 0)));
+}
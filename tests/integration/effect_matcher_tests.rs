@@ -0,0 +1,70 @@
+use super::TestEvent;
+use oxide_mvu::{Effect, EffectMatcher};
+
+#[test]
+fn given_no_effect_should_only_match_the_none_matcher() {
+    let effect: Effect<TestEvent> = Effect::none();
+
+    assert!(EffectMatcher::None.matches(&effect));
+    assert!(!EffectMatcher::Just(TestEvent::Increment).matches(&effect));
+}
+
+#[test]
+fn given_a_just_effect_should_only_match_a_just_matcher_with_an_equal_event() {
+    let effect = Effect::just(TestEvent::Set(1));
+
+    assert!(EffectMatcher::Just(TestEvent::Set(1)).matches(&effect));
+    assert!(!EffectMatcher::Just(TestEvent::Set(2)).matches(&effect));
+    assert!(!EffectMatcher::None.matches(&effect));
+}
+
+#[test]
+fn given_a_nested_batch_effect_should_match_a_batch_matcher_with_the_same_structure() {
+    let effect = Effect::batch(vec![
+        Effect::just(TestEvent::Increment),
+        Effect::batch(vec![Effect::none(), Effect::just(TestEvent::Set(3))]),
+    ]);
+
+    assert!(EffectMatcher::Batch(vec![
+        EffectMatcher::Just(TestEvent::Increment),
+        EffectMatcher::Batch(vec![
+            EffectMatcher::None,
+            EffectMatcher::Just(TestEvent::Set(3)),
+        ]),
+    ])
+    .matches(&effect));
+    assert!(
+        !EffectMatcher::Batch(vec![EffectMatcher::Just(TestEvent::Increment)]).matches(&effect)
+    );
+}
+
+#[test]
+fn given_an_async_effect_should_not_match_any_matcher() {
+    let effect = Effect::from_async(|emitter| async move { emitter.emit(TestEvent::Increment) });
+
+    assert!(!EffectMatcher::Just(TestEvent::Increment).matches(&effect));
+    assert!(!EffectMatcher::None.matches(&effect));
+}
+
+#[test]
+fn given_effects_added_together_should_match_a_flat_batch_in_order() {
+    let mut effect = Effect::none() + Effect::just(TestEvent::Increment);
+    effect += Effect::just(TestEvent::Set(1));
+    effect += Effect::none();
+    effect += Effect::just(TestEvent::Set(2));
+
+    assert!(EffectMatcher::Batch(vec![
+        EffectMatcher::Just(TestEvent::Increment),
+        EffectMatcher::Just(TestEvent::Set(1)),
+        EffectMatcher::Just(TestEvent::Set(2)),
+    ])
+    .matches(&effect));
+}
+
+#[test]
+fn given_a_labeled_effect_should_match_as_if_it_were_unlabeled() {
+    let effect = Effect::just(TestEvent::Increment).labeled("increment");
+
+    assert!(EffectMatcher::Just(TestEvent::Increment).matches(&effect));
+    assert!(!EffectMatcher::None.matches(&effect));
+}
@@ -0,0 +1,108 @@
+use super::{
+    build_test_logic, MockEffectsDependency, MockInitialEffectsDependency, TestEvent, TestLogic,
+    TestModel,
+};
+use oxide_mvu::{create_test_spawner, Coalescer, Effect, TestMvuRuntime, TestRenderer};
+
+use std::sync::{Arc, Mutex};
+
+fn build_self_emitting_logic() -> TestLogic {
+    let mut initial_effects = MockInitialEffectsDependency::new();
+    initial_effects
+        .expect_on_init()
+        .return_once(|| Effect::just(TestEvent::Increment));
+    let mut effects = MockEffectsDependency::new();
+    effects
+        .expect_on_increment_side_effect()
+        .returning(|| Effect::just(TestEvent::Increment));
+
+    TestLogic {
+        initial_effects: Box::new(initial_effects),
+        effects: Box::new(effects),
+    }
+}
+
+#[test]
+fn given_an_event_that_re_emits_itself_should_report_the_loop_at_the_threshold() {
+    let renderer = TestRenderer::new();
+    let detections = Arc::new(Mutex::new(Vec::new()));
+
+    let reported = detections.clone();
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        build_self_emitting_logic(),
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_emit_loop_detection(5, move |event: &TestEvent, depth| {
+        reported.lock().unwrap().push((event.clone(), depth));
+    })
+    .run();
+
+    driver.assert_at_fixed_point(100);
+
+    assert_eq!(*detections.lock().unwrap(), vec![(TestEvent::Increment, 5)]);
+    renderer.with_renders(|renders| {
+        assert_eq!(renders.last().unwrap().count, 4);
+    });
+}
+
+#[test]
+fn given_identical_events_processed_in_separate_drains_should_not_report_a_loop() {
+    let renderer = TestRenderer::new();
+    let detections = Arc::new(Mutex::new(Vec::new()));
+
+    let reported = detections.clone();
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        build_test_logic(Effect::none()),
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_emit_loop_detection(3, move |event: &TestEvent, depth| {
+        reported.lock().unwrap().push((event.clone(), depth));
+    })
+    .run();
+
+    for _ in 0..5 {
+        renderer.with_renders(|renders| (renders[0].on_increment)());
+        driver.process_events();
+    }
+
+    assert!(detections.lock().unwrap().is_empty());
+    renderer.with_renders(|renders| {
+        assert_eq!(renders.last().unwrap().count, 5);
+    });
+}
+
+struct KeepAll;
+
+impl Coalescer<TestEvent> for KeepAll {
+    fn coalesce(&self, _pending: &mut Vec<TestEvent>) {}
+}
+
+#[test]
+fn given_a_coalescer_and_an_event_that_re_emits_itself_should_report_the_loop_at_the_threshold() {
+    let renderer = TestRenderer::new();
+    let detections = Arc::new(Mutex::new(Vec::new()));
+
+    let reported = detections.clone();
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        build_self_emitting_logic(),
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_coalescer(KeepAll)
+    .with_emit_loop_detection(5, move |event: &TestEvent, depth| {
+        reported.lock().unwrap().push((event.clone(), depth));
+    })
+    .run();
+
+    driver.assert_at_fixed_point(100);
+
+    assert_eq!(*detections.lock().unwrap(), vec![(TestEvent::Increment, 5)]);
+    renderer.with_renders(|renders| {
+        assert_eq!(renders.last().unwrap().count, 4);
+    });
+}
@@ -0,0 +1,26 @@
+use super::{build_integration_test, TestEvent};
+use oxide_mvu::block_on;
+
+use futures::{stream, StreamExt};
+
+#[test]
+fn given_a_stream_forwarded_into_an_emitter_sink_should_process_every_item() {
+    let mut test = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+    let sink = test.driver.handle().emitter().into_sink();
+
+    let events = stream::iter(vec![
+        TestEvent::Increment,
+        TestEvent::Increment,
+        TestEvent::Increment,
+    ]);
+    block_on(events.map(Ok).forward(sink)).unwrap();
+    test.driver.process_events();
+
+    assert_eq!(test.renders.count(), 4);
+    test.renders.with_renders(|renders| {
+        assert_eq!(renders[3].count, 3);
+    });
+}
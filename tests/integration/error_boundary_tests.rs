@@ -0,0 +1,94 @@
+use super::{TestEvent, TestModel, TestProps};
+use oxide_mvu::{
+    create_test_spawner, Effect, Emitter, ErrorAction, ErrorBoundary, FnLogic, Renderer,
+    RuntimeError, TestMvuRuntime, TestRenderer,
+};
+
+use std::sync::{Arc, Mutex};
+
+/// Renderer failing on a count of 7.
+struct PanickingRenderer(TestRenderer<TestProps>);
+
+impl Renderer<TestProps> for PanickingRenderer {
+    fn render(&mut self, props: TestProps) {
+        if props.count == 7 {
+            panic!("bad frame");
+        }
+        self.0.render(props);
+    }
+}
+
+/// Boundary recording each error and routing each kind to its own action.
+struct RecordingBoundary(Arc<Mutex<Vec<RuntimeError>>>);
+
+impl ErrorBoundary<TestEvent, TestModel> for RecordingBoundary {
+    fn handle(&self, error: RuntimeError, _model: &TestModel) -> ErrorAction<TestEvent, TestModel> {
+        self.0.lock().unwrap().push(error.clone());
+        match error {
+            RuntimeError::Update(_) => ErrorAction::Emit(TestEvent::Set(42)),
+            RuntimeError::View(_) => ErrorAction::SetModel(TestModel { count: 99 }),
+            RuntimeError::Render(_) => ErrorAction::Shutdown,
+        }
+    }
+}
+
+#[test]
+fn given_an_error_boundary_should_route_each_kind_of_failure_to_its_action() {
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let renderer = TestRenderer::new();
+    let logic = FnLogic::new(
+        |model: TestModel| (model, Effect::none()),
+        |event: TestEvent, model: &TestModel| match event {
+            TestEvent::Increment => (
+                TestModel {
+                    count: model.count + 1,
+                },
+                Effect::none(),
+            ),
+            TestEvent::Set(count) if count < 0 => panic!("negative count"),
+            TestEvent::Set(count) => (TestModel { count }, Effect::none()),
+        },
+        |model: &TestModel, emitter: &Emitter<TestEvent>| {
+            if model.count == 13 {
+                panic!("unlucky");
+            }
+            let emitter = emitter.clone();
+            TestProps {
+                count: model.count,
+                on_increment: Box::new(move || emitter.emit(TestEvent::Increment)),
+            }
+        },
+    );
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        logic,
+        PanickingRenderer(renderer.clone()),
+        create_test_spawner(),
+    )
+    .with_error_boundary(RecordingBoundary(errors.clone()))
+    .run();
+    let emitter = driver.handle().emitter();
+
+    for event in [
+        TestEvent::Set(-1),
+        TestEvent::Set(13),
+        TestEvent::Set(7),
+        TestEvent::Increment,
+    ] {
+        emitter.emit(event);
+        driver.process_events();
+    }
+
+    assert_eq!(
+        *errors.lock().unwrap(),
+        vec![
+            RuntimeError::Update("negative count".to_string()),
+            RuntimeError::View("unlucky".to_string()),
+            RuntimeError::Render("bad frame".to_string()),
+        ]
+    );
+    renderer.with_renders(|renders| {
+        let counts: Vec<i32> = renders.iter().map(|props| props.count).collect();
+        assert_eq!(counts, vec![0, 42, 99]);
+    });
+}
@@ -0,0 +1,55 @@
+use oxide_mvu::{FallbackRenderer, Renderer, TestRenderer};
+use std::sync::{Arc, Mutex};
+
+struct PanicsOnSecondRender {
+    renders: usize,
+}
+
+impl Renderer<i32> for PanicsOnSecondRender {
+    fn render(&mut self, _props: i32) {
+        self.renders += 1;
+        if self.renders == 2 {
+            panic!("primary renderer failure");
+        }
+    }
+}
+
+#[test]
+fn given_a_primary_renderer_that_panics_should_render_that_and_subsequent_props_on_the_fallback() {
+    let fallback = TestRenderer::new();
+    let mut renderer = FallbackRenderer::new(PanicsOnSecondRender { renders: 0 }, fallback.clone());
+
+    renderer.render(1);
+    assert!(!renderer.is_using_fallback());
+    assert_eq!(fallback.count(), 0);
+
+    renderer.render(2);
+    renderer.render(3);
+
+    assert!(renderer.is_using_fallback());
+    fallback.with_renders(|renders| {
+        assert_eq!(renders, &vec![2, 3]);
+    });
+}
+
+#[test]
+fn given_an_on_fallback_callback_should_report_the_panic_message_once_when_switching() {
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let mut renderer =
+        FallbackRenderer::new(PanicsOnSecondRender { renders: 0 }, TestRenderer::new())
+            .on_fallback({
+                let reported = reported.clone();
+                move |message| reported.lock().unwrap().push(message.to_string())
+            });
+
+    renderer.render(1);
+    assert!(reported.lock().unwrap().is_empty());
+
+    renderer.render(2);
+    renderer.render(3);
+
+    assert_eq!(
+        *reported.lock().unwrap(),
+        vec!["primary renderer failure".to_string()]
+    );
+}
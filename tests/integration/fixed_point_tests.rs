@@ -0,0 +1,54 @@
+use super::{build_integration_test, TestEvent};
+use oxide_mvu::Effect;
+
+#[test]
+fn given_convergent_logic_should_reach_a_fixed_point() {
+    let mut test = build_integration_test()
+        .given_an_initial_effect(Effect::batch(vec![
+            Effect::just(TestEvent::Increment),
+            Effect::just(TestEvent::Increment),
+        ]))
+        .given_a_noop_on_increment_side_effect()
+        .build();
+
+    test.driver.assert_at_fixed_point(10);
+
+    test.renders.with_renders(|renders| {
+        assert_eq!(renders.last().unwrap().count, 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "runtime did not reach a fixed point after processing 10 events")]
+fn given_logic_that_always_emits_again_should_fail_to_reach_a_fixed_point() {
+    let mut test = build_integration_test()
+        .given_an_initial_effect(Effect::just(TestEvent::Increment))
+        .given_an_on_increment_side_effect_that_increments_again()
+        .build();
+
+    test.driver.assert_at_fixed_point(10);
+}
+
+#[test]
+fn given_a_chain_of_follow_up_effects_when_processing_events_should_settle_the_whole_chain() {
+    let mut test = build_integration_test()
+        .given_an_initial_effect(Effect::just(TestEvent::Increment))
+        .build();
+    {
+        let mut effects = test.mock_effects_dependency.lock().unwrap();
+        effects
+            .expect_on_increment_side_effect()
+            .times(3)
+            .returning(|| Effect::just(TestEvent::Increment));
+        effects
+            .expect_on_increment_side_effect()
+            .returning(Effect::none);
+    }
+
+    test.driver.process_events();
+
+    assert!(test.driver.handle().take_pending().is_empty());
+    test.renders.with_renders(|renders| {
+        assert_eq!(renders.last().unwrap().count, 4);
+    });
+}
@@ -0,0 +1,53 @@
+use super::{build_integration_test, TestEvent, TestModel, TestProps};
+use oxide_mvu::{create_test_spawner, Effect, Emitter, FnLogic, TestMvuRuntime, TestRenderer};
+
+#[test]
+fn given_a_counter_built_from_closures_should_render_like_the_struct_based_logic() {
+    let renderer = TestRenderer::new();
+    let logic = FnLogic::new(
+        |model: TestModel| (model, Effect::none()),
+        |event: TestEvent, model: &TestModel| match event {
+            TestEvent::Increment => (
+                TestModel {
+                    count: model.count + 1,
+                },
+                Effect::none(),
+            ),
+            TestEvent::Set(count) => (TestModel { count }, Effect::none()),
+        },
+        |model: &TestModel, emitter: &Emitter<TestEvent>| {
+            let emitter = emitter.clone();
+            TestProps {
+                count: model.count,
+                on_increment: Box::new(move || emitter.emit(TestEvent::Increment)),
+            }
+        },
+    );
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        logic,
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .run();
+    let mut test = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+
+    for _ in 0..3 {
+        renderer.with_renders(|renders| (renders[0].on_increment)());
+        test.renders
+            .with_renders(|renders| (renders[0].on_increment)());
+    }
+    driver.process_events();
+    test.driver.process_events();
+
+    let closure_counts = renderer
+        .with_renders(|renders| renders.iter().map(|props| props.count).collect::<Vec<_>>());
+    let struct_counts = test
+        .renders
+        .with_renders(|renders| renders.iter().map(|props| props.count).collect::<Vec<_>>());
+    assert_eq!(closure_counts, vec![0, 1, 2, 3]);
+    assert_eq!(closure_counts, struct_counts);
+}
@@ -0,0 +1,75 @@
+use super::{build_test_logic, TestEvent, TestModel};
+use oxide_mvu::{
+    create_test_spawner, Effect, InitRenderMode, MvuRuntime, TestMvuRuntime, TestRenderer,
+};
+
+use futures::task::noop_waker;
+use std::task::Context;
+
+#[test]
+fn given_before_effects_mode_should_render_the_initial_model_before_the_initial_event() {
+    let renderer = TestRenderer::new();
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        build_test_logic(Effect::just(TestEvent::Increment)),
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_init_render_mode(InitRenderMode::BeforeEffects)
+    .run();
+    driver.process_events();
+
+    renderer.with_renders(|renders| {
+        let counts: Vec<i32> = renders.iter().map(|props| props.count).collect();
+        assert_eq!(counts, vec![0, 1]);
+    });
+}
+
+#[test]
+fn given_after_effects_mode_should_only_render_the_model_settled_by_the_initial_event() {
+    let renderer = TestRenderer::new();
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        build_test_logic(Effect::just(TestEvent::Increment)),
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_init_render_mode(InitRenderMode::AfterEffects)
+    .run();
+    driver.process_events();
+
+    renderer.with_renders(|renders| {
+        let counts: Vec<i32> = renders.iter().map(|props| props.count).collect();
+        assert_eq!(counts, vec![1]);
+    });
+}
+
+#[test]
+fn given_after_effects_mode_and_rendering_suspended_before_start_should_keep_it_suspended() {
+    let renderer = TestRenderer::new();
+    let mut runtime = MvuRuntime::new(
+        TestModel { count: 0 },
+        build_test_logic(Effect::just(TestEvent::Increment)),
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_init_render_mode(InitRenderMode::AfterEffects);
+    let handle = runtime.handle();
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    handle.suspend_rendering();
+    let _ = runtime.poll_once(&mut cx);
+    handle.emitter().emit(TestEvent::Increment);
+    let _ = runtime.poll_once(&mut cx);
+
+    assert_eq!(renderer.count(), 0);
+
+    handle.resume_rendering();
+    let _ = runtime.poll_once(&mut cx);
+
+    renderer.with_renders(|renders| {
+        let counts: Vec<i32> = renders.iter().map(|props| props.count).collect();
+        assert_eq!(counts, vec![2]);
+    });
+}
@@ -8,8 +8,46 @@ use std::future::Future;
 use std::pin::Pin;
 use std::sync::{Arc, Mutex};
 
+mod ask_tests;
+mod batch_update_tests;
+mod coalescer_tests;
+mod cooperative_spawner_tests;
+mod diff_test_renderer_tests;
+mod dispatch_logic_tests;
+mod dry_update_tests;
 mod effect_dispatch_tests;
+mod effect_fanout_tests;
+mod effect_matcher_tests;
+mod emit_loop_tests;
+#[cfg(feature = "futures")]
+mod emitter_sink_tests;
+#[cfg(not(feature = "no_std"))]
+mod error_boundary_tests;
+#[cfg(not(feature = "no_std"))]
+mod fallback_renderer_tests;
+mod fixed_point_tests;
+mod fn_logic_tests;
+mod init_render_mode_tests;
+mod multi_view_tests;
+#[cfg(not(feature = "no_std"))]
+mod panic_policy_tests;
+mod prerender_tests;
+mod props_transform_tests;
 mod reduction_and_emission_tests;
+mod render_cause_tests;
+mod render_scheduler_tests;
+mod render_stream_tests;
+mod render_timing_tests;
+mod replay_tests;
+mod ring_buffer_renderer_tests;
+mod routed_tests;
+mod router_logic_tests;
+mod runtime_handle_tests;
+mod runtime_polling_tests;
+mod strict_view_tests;
+mod throttled_renderer_tests;
+mod view_composer_tests;
+mod view_key_tests;
 
 pub(crate) struct IntegrationTestStubbing {
     mock_initial_effects_dependency: MockInitialEffectsDependency,
@@ -71,6 +109,14 @@ impl IntegrationTestStubbing {
         self
     }
 
+    pub(crate) fn given_an_on_increment_side_effect_that_increments_again(mut self) -> Self {
+        self.mock_effects_dependency
+            .expect_on_increment_side_effect()
+            .returning(|| Effect::just(TestEvent::Increment));
+
+        self
+    }
+
     pub(crate) fn build(self) -> IntegrationTestHarness {
         self.create_integration_test_harness()
     }
@@ -112,6 +158,22 @@ impl IntegrationTestHarness {
     }
 }
 
+pub(crate) fn build_test_logic(initial_effect: Effect<TestEvent>) -> TestLogic {
+    let mut initial_effects = MockInitialEffectsDependency::new();
+    initial_effects
+        .expect_on_init()
+        .return_once(move || initial_effect);
+    let mut effects = MockEffectsDependency::new();
+    effects
+        .expect_on_increment_side_effect()
+        .returning(Effect::none);
+
+    TestLogic {
+        initial_effects: Box::new(initial_effects),
+        effects: Box::new(effects),
+    }
+}
+
 pub(crate) fn build_integration_test() -> IntegrationTestStubbing {
     IntegrationTestStubbing {
         mock_initial_effects_dependency: MockInitialEffectsDependency::new(),
@@ -0,0 +1,39 @@
+use super::{build_test_logic, TestModel, TestProps};
+use oxide_mvu::{create_test_spawner, Effect, TestMvuRuntime, TestRenderer};
+
+struct MinimapProps {
+    doubled_count: i32,
+}
+
+#[test]
+fn given_a_secondary_view_should_render_both_views_on_each_update() {
+    let primary = TestRenderer::<TestProps>::new();
+    let minimap = TestRenderer::<MinimapProps>::new();
+
+    let mut runtime = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        build_test_logic(Effect::none()),
+        primary.clone(),
+        create_test_spawner(),
+    );
+    runtime.add_view(
+        |model: &TestModel, _| MinimapProps {
+            doubled_count: model.count * 2,
+        },
+        Box::new(minimap.clone()),
+    );
+    let mut driver = runtime.run();
+
+    primary.with_renders(|renders| (renders[0].on_increment)());
+    driver.process_events();
+
+    assert_eq!(primary.count(), 2);
+    assert_eq!(minimap.count(), 2);
+    primary.with_renders(|renders| {
+        assert_eq!(renders[1].count, 1);
+    });
+    minimap.with_renders(|renders| {
+        assert_eq!(renders[0].doubled_count, 0);
+        assert_eq!(renders[1].doubled_count, 2);
+    });
+}
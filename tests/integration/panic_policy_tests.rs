@@ -0,0 +1,73 @@
+use super::{TestEvent, TestModel, TestProps};
+use oxide_mvu::{
+    create_test_spawner, Effect, Emitter, FnLogic, PanicPolicy, TestMvuRuntime, TestRenderer,
+};
+
+fn build_driver_panicking_on_negative_set(
+    policy: PanicPolicy,
+    renderer: &TestRenderer<TestProps>,
+) -> impl FnMut(TestEvent) {
+    let logic = FnLogic::new(
+        |model: TestModel| (model, Effect::none()),
+        |event: TestEvent, model: &TestModel| match event {
+            TestEvent::Increment => (
+                TestModel {
+                    count: model.count + 1,
+                },
+                Effect::none(),
+            ),
+            TestEvent::Set(count) if count < 0 => panic!("negative count"),
+            TestEvent::Set(count) => (TestModel { count }, Effect::none()),
+        },
+        |model: &TestModel, emitter: &Emitter<TestEvent>| {
+            let emitter = emitter.clone();
+            TestProps {
+                count: model.count,
+                on_increment: Box::new(move || emitter.emit(TestEvent::Increment)),
+            }
+        },
+    );
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        logic,
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_panic_policy(policy)
+    .run();
+
+    move |event| {
+        driver.handle().emitter().emit(event);
+        driver.process_events();
+    }
+}
+
+#[test]
+fn given_skip_event_policy_when_update_panics_should_keep_processing_from_the_previous_model() {
+    let renderer = TestRenderer::new();
+    let mut send = build_driver_panicking_on_negative_set(PanicPolicy::SkipEvent, &renderer);
+
+    send(TestEvent::Increment);
+    send(TestEvent::Set(-1));
+    send(TestEvent::Increment);
+
+    renderer.with_renders(|renders| {
+        let counts: Vec<i32> = renders.iter().map(|props| props.count).collect();
+        assert_eq!(counts, vec![0, 1, 2]);
+    });
+}
+
+#[test]
+fn given_shutdown_policy_when_update_panics_should_stop_processing_events() {
+    let renderer = TestRenderer::new();
+    let mut send = build_driver_panicking_on_negative_set(PanicPolicy::Shutdown, &renderer);
+
+    send(TestEvent::Increment);
+    send(TestEvent::Set(-1));
+    send(TestEvent::Increment);
+
+    renderer.with_renders(|renders| {
+        let counts: Vec<i32> = renders.iter().map(|props| props.count).collect();
+        assert_eq!(counts, vec![0, 1]);
+    });
+}
@@ -0,0 +1,30 @@
+use super::{build_test_logic, TestEvent, TestModel};
+use oxide_mvu::{prerender, Effect};
+
+#[test]
+fn given_synchronous_init_events_should_prerender_the_resulting_props() {
+    let logic = build_test_logic(Effect::batch(vec![
+        Effect::just(TestEvent::Set(41)),
+        Effect::just(TestEvent::Increment),
+    ]));
+
+    let html = prerender(&logic, TestModel { count: 0 }, |props| {
+        format!("<button>count: {}</button>", props.count)
+    });
+
+    assert_eq!(html, "<button>count: 42</button>");
+}
+
+#[test]
+fn given_an_async_init_effect_that_is_still_pending_should_prerender_without_its_events() {
+    let logic = build_test_logic(Effect::from_async(|emitter| async move {
+        futures::pending!();
+        emitter.emit(TestEvent::Set(99));
+    }));
+
+    let html = prerender(&logic, TestModel { count: 5 }, |props| {
+        format!("<button>count: {}</button>", props.count)
+    });
+
+    assert_eq!(html, "<button>count: 5</button>");
+}
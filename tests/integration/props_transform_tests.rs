@@ -0,0 +1,34 @@
+use super::{build_test_logic, TestModel, TestProps};
+use oxide_mvu::{create_test_spawner, Effect, PropsTransform, TestMvuRuntime, TestRenderer};
+
+struct TimesTen;
+
+impl PropsTransform<TestProps> for TimesTen {
+    fn transform(&self, props: TestProps) -> TestProps {
+        TestProps {
+            count: props.count * 10,
+            ..props
+        }
+    }
+}
+
+#[test]
+fn given_a_props_transform_should_render_transformed_props_including_the_initial_render() {
+    let renderer = TestRenderer::new();
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 1 },
+        build_test_logic(Effect::none()),
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_props_transform(TimesTen)
+    .run();
+
+    renderer.with_renders(|renders| (renders[0].on_increment)());
+    driver.process_events();
+
+    renderer.with_renders(|renders| {
+        assert_eq!(renders[0].count, 10);
+        assert_eq!(renders[1].count, 20);
+    });
+}
@@ -0,0 +1,70 @@
+use oxide_mvu::{create_test_spawner, Effect, Emitter, MvuLogic, TestMvuRuntime, TestRenderer};
+
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone)]
+enum Event {
+    Increment,
+    Hover,
+}
+
+#[derive(Clone)]
+struct Model {
+    count: i32,
+    hovered: bool,
+}
+
+struct Logic;
+
+impl MvuLogic<Event, Model, i32> for Logic {
+    fn init(&self, model: Model) -> (Model, Effect<Event>) {
+        (model, Effect::none())
+    }
+
+    fn update(&self, event: Event, model: &Model) -> (Model, Effect<Event>) {
+        let model = match event {
+            Event::Increment => Model {
+                count: model.count + 1,
+                ..model.clone()
+            },
+            Event::Hover => Model {
+                hovered: !model.hovered,
+                ..model.clone()
+            },
+        };
+        (model, Effect::none())
+    }
+
+    fn view(&self, model: &Model, _emitter: &Emitter<Event>) -> i32 {
+        model.count
+    }
+
+    fn view_deps(&self, model: &Model) -> Vec<(&'static str, u64)> {
+        vec![("count", model.count as u64)]
+    }
+}
+
+#[test]
+fn given_declared_view_deps_should_report_only_changes_to_declared_fields() {
+    let causes = Arc::new(Mutex::new(Vec::new()));
+    let recorded = causes.clone();
+    let mut driver = TestMvuRuntime::new(
+        Model {
+            count: 0,
+            hovered: false,
+        },
+        Logic,
+        TestRenderer::new(),
+        create_test_spawner(),
+    )
+    .with_render_cause(move |changed| recorded.lock().unwrap().push(changed.to_vec()))
+    .run();
+    let events = driver.handle().emitter();
+
+    events.emit(Event::Increment);
+    driver.process_events();
+    events.emit(Event::Hover);
+    driver.process_events();
+
+    assert_eq!(*causes.lock().unwrap(), vec![vec!["count"], vec![]]);
+}
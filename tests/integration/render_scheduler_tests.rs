@@ -0,0 +1,48 @@
+use super::{build_test_logic, TestModel, TestProps};
+use oxide_mvu::{create_test_spawner, Effect, TestMvuRuntime, TestRenderer};
+
+use std::sync::{Arc, Mutex};
+
+type ScheduledRenders = Arc<Mutex<Vec<Box<dyn FnOnce() + Send>>>>;
+
+fn fire(scheduled: &ScheduledRenders) {
+    for render in scheduled.lock().unwrap().drain(..) {
+        render();
+    }
+}
+
+#[test]
+fn given_a_render_scheduler_should_render_only_when_the_scheduled_callback_runs() {
+    let scheduled: ScheduledRenders = Arc::new(Mutex::new(Vec::new()));
+    let renderer = TestRenderer::<TestProps>::new();
+
+    let queue = scheduled.clone();
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        build_test_logic(Effect::none()),
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_render_scheduler(move |render| queue.lock().unwrap().push(render))
+    .run();
+
+    assert_eq!(renderer.count(), 0);
+    fire(&scheduled);
+    driver.process_events();
+    assert_eq!(renderer.count(), 1);
+
+    renderer.with_renders(|renders| (renders[0].on_increment)());
+    renderer.with_renders(|renders| (renders[0].on_increment)());
+    driver.process_events();
+
+    assert_eq!(renderer.count(), 1);
+    assert_eq!(scheduled.lock().unwrap().len(), 1);
+
+    fire(&scheduled);
+    driver.process_events();
+
+    assert_eq!(renderer.count(), 2);
+    renderer.with_renders(|renders| {
+        assert_eq!(renders[1].count, 2);
+    });
+}
@@ -0,0 +1,38 @@
+use oxide_mvu::{create_test_spawner, Effect, Emitter, MvuLogic, TestMvuRuntime, TestRenderer};
+
+enum CounterEvent {
+    Add(i32),
+}
+
+struct Counter;
+
+impl MvuLogic<CounterEvent, i32, i32> for Counter {
+    fn init(&self, model: i32) -> (i32, Effect<CounterEvent>) {
+        (model, Effect::none())
+    }
+
+    fn update(&self, event: CounterEvent, model: &i32) -> (i32, Effect<CounterEvent>) {
+        match event {
+            CounterEvent::Add(amount) => (model + amount, Effect::none()),
+        }
+    }
+
+    fn view(&self, model: &i32, _emitter: &Emitter<CounterEvent>) -> i32 {
+        *model
+    }
+}
+
+#[test]
+fn given_three_queued_events_should_yield_one_render_per_event() {
+    let renderer = TestRenderer::new();
+    let mut driver = TestMvuRuntime::new(0, Counter, renderer.clone(), create_test_spawner()).run();
+
+    let emitter = driver.handle().emitter();
+    emitter.emit(CounterEvent::Add(1));
+    emitter.emit(CounterEvent::Add(2));
+    emitter.emit(CounterEvent::Add(3));
+
+    let renders: Vec<i32> = driver.render_stream(&renderer).collect();
+
+    assert_eq!(renders, vec![1, 3, 6]);
+}
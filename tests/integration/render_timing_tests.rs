@@ -0,0 +1,65 @@
+use super::{build_test_logic, TestModel, TestProps};
+use oxide_mvu::{create_test_spawner, Effect, Renderer, TestClock, TestMvuRuntime};
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Renderer that takes a scripted amount of simulated time for each render.
+#[derive(Clone)]
+struct SimulatedRenderer {
+    clock: TestClock,
+    render_durations: Arc<Mutex<Vec<Duration>>>,
+    renders: Arc<Mutex<Vec<TestProps>>>,
+}
+
+impl Renderer<TestProps> for SimulatedRenderer {
+    fn render(&mut self, props: TestProps) {
+        let duration = self.render_durations.lock().unwrap().remove(0);
+        self.clock.advance(duration);
+        self.renders.lock().unwrap().push(props);
+    }
+}
+
+#[test]
+fn given_render_timing_when_a_render_exceeds_the_threshold_should_report_only_the_slow_render() {
+    let clock = TestClock::new();
+    let slow_renders = Arc::new(Mutex::new(Vec::new()));
+    let renderer = SimulatedRenderer {
+        clock: clock.clone(),
+        render_durations: Arc::new(Mutex::new(vec![
+            Duration::from_millis(5),
+            Duration::from_millis(40),
+        ])),
+        renders: Arc::new(Mutex::new(Vec::new())),
+    };
+
+    let reported = slow_renders.clone();
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        build_test_logic(Effect::none()),
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_render_timing(clock, Duration::from_millis(16), move |duration| {
+        reported.lock().unwrap().push(duration);
+    })
+    .run();
+
+    assert_eq!(
+        driver.last_render_duration(),
+        Some(Duration::from_millis(5))
+    );
+    assert!(slow_renders.lock().unwrap().is_empty());
+
+    (renderer.renders.lock().unwrap()[0].on_increment)();
+    driver.process_events();
+
+    assert_eq!(
+        driver.last_render_duration(),
+        Some(Duration::from_millis(40))
+    );
+    assert_eq!(
+        *slow_renders.lock().unwrap(),
+        vec![Duration::from_millis(40)]
+    );
+}
@@ -0,0 +1,33 @@
+use super::{build_integration_test, build_test_logic, TestEvent, TestModel};
+use oxide_mvu::{replay, Effect, ModelAccess};
+
+#[test]
+fn given_a_recorded_session_when_replayed_should_rebuild_the_same_model() {
+    let mut test = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+    let handle = test.driver.handle();
+    let access = ModelAccess::new(&handle);
+    let session = vec![
+        TestEvent::Increment,
+        TestEvent::Set(7),
+        TestEvent::Increment,
+        TestEvent::Increment,
+    ];
+
+    let emitter = handle.emitter();
+    for event in session.clone() {
+        emitter.emit(event);
+    }
+    test.driver.process_events();
+
+    let rebuilt = replay(
+        &build_test_logic(Effect::none()),
+        TestModel { count: 0 },
+        session,
+    );
+
+    assert_eq!(rebuilt, TestModel { count: 9 });
+    assert_eq!(access.read(|model| model.clone()), Some(rebuilt));
+}
@@ -0,0 +1,16 @@
+use oxide_mvu::{Renderer, RingBufferRenderer, TestRenderer};
+
+#[test]
+fn given_more_renders_than_capacity_should_retain_only_the_latest_in_order() {
+    let inner = TestRenderer::new();
+    let mut renderer = RingBufferRenderer::new(inner.clone(), 3);
+
+    for props in 1..=5 {
+        renderer.render(props);
+    }
+
+    assert_eq!(renderer.recent(), vec![3, 4, 5]);
+    inner.with_renders(|renders| {
+        assert_eq!(renders, &vec![1, 2, 3, 4, 5]);
+    });
+}
@@ -0,0 +1,91 @@
+use super::{TestEvent, TestModel, TestProps};
+use oxide_mvu::{
+    create_test_spawner, Effect, Emitter, FnLogic, MvuLogic, Routed, TestMvuRuntime, TestRenderer,
+};
+
+#[derive(Clone)]
+struct ListModel {
+    counters: Vec<(u32, TestModel)>,
+}
+
+enum ListEvent {
+    Counter(Routed<u32, TestEvent>),
+}
+
+fn counter() -> impl MvuLogic<TestEvent, TestModel, TestProps> {
+    FnLogic::new(
+        |model: TestModel| (model, Effect::none()),
+        |event: TestEvent, model: &TestModel| match event {
+            TestEvent::Increment => (
+                TestModel {
+                    count: model.count + 1,
+                },
+                Effect::none(),
+            ),
+            TestEvent::Set(count) => (TestModel { count }, Effect::just(TestEvent::Increment)),
+        },
+        |model: &TestModel, emitter: &Emitter<TestEvent>| {
+            let emitter = emitter.clone();
+            TestProps {
+                count: model.count,
+                on_increment: Box::new(move || emitter.emit(TestEvent::Increment)),
+            }
+        },
+    )
+}
+
+#[test]
+fn given_three_routed_counters_should_route_each_event_to_the_child_with_its_id() {
+    let renderer = TestRenderer::new();
+    let logic = FnLogic::new(
+        |model: ListModel| (model, Effect::none()),
+        |ListEvent::Counter(routed), model: &ListModel| {
+            let mut model = model.clone();
+            let effect = routed.update(&counter(), &mut model.counters, ListEvent::Counter);
+            (model, effect)
+        },
+        |model: &ListModel, emitter: &Emitter<ListEvent>| {
+            Routed::views(&counter(), &model.counters, emitter, ListEvent::Counter)
+        },
+    );
+    let mut driver = TestMvuRuntime::new(
+        ListModel {
+            counters: vec![
+                (1, TestModel { count: 0 }),
+                (2, TestModel { count: 0 }),
+                (3, TestModel { count: 0 }),
+            ],
+        },
+        logic,
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .run();
+
+    renderer.with_renders(|renders| {
+        (renders[0][1].on_increment)();
+        (renders[0][1].on_increment)();
+        (renders[0][2].on_increment)();
+    });
+    // The effect of a routed child is routed back to the same child
+    driver
+        .handle()
+        .emitter()
+        .emit(ListEvent::Counter(Routed::new(1, TestEvent::Set(10))));
+    // Events for unknown ids are ignored
+    driver
+        .handle()
+        .emitter()
+        .emit(ListEvent::Counter(Routed::new(4, TestEvent::Increment)));
+    driver.process_events();
+
+    renderer.with_renders(|renders| {
+        let counts: Vec<i32> = renders
+            .last()
+            .unwrap()
+            .iter()
+            .map(|props| props.count)
+            .collect();
+        assert_eq!(counts, vec![11, 2, 1]);
+    });
+}
@@ -0,0 +1,260 @@
+use oxide_mvu::{
+    create_test_spawner, Effect, Emitter, FnLogic, Lens, MvuLogic, RouterLogic, TestMvuRuntime,
+    TestRenderer,
+};
+
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Route {
+    Counter,
+    Toggle,
+    About,
+}
+
+#[derive(Clone)]
+struct AppModel {
+    route: Route,
+    count: i32,
+    enabled: bool,
+}
+
+enum CounterEvent {
+    Increment,
+    IncrementTwice,
+}
+
+enum ToggleEvent {
+    Flip,
+}
+
+enum AppEvent {
+    Navigate(Route),
+    Counter(CounterEvent),
+    Toggle(ToggleEvent),
+}
+
+fn router() -> impl MvuLogic<AppEvent, AppModel, String> {
+    let base = FnLogic::new(
+        |model: AppModel| (model, Effect::none()),
+        |event: AppEvent, model: &AppModel| match event {
+            AppEvent::Navigate(route) => (
+                AppModel {
+                    route,
+                    ..model.clone()
+                },
+                Effect::none(),
+            ),
+            // Events of inactive children are ignored
+            _ => (model.clone(), Effect::none()),
+        },
+        |_model: &AppModel, _emitter: &Emitter<AppEvent>| String::from("about"),
+    );
+    let counter = FnLogic::new(
+        |count: i32| (count, Effect::none()),
+        |event: CounterEvent, count: &i32| match event {
+            CounterEvent::Increment => (count + 1, Effect::none()),
+            CounterEvent::IncrementTwice => (count + 1, Effect::just(CounterEvent::Increment)),
+        },
+        |count: &i32, _emitter: &Emitter<CounterEvent>| *count,
+    );
+    let toggle = FnLogic::new(
+        |enabled: bool| (enabled, Effect::none()),
+        |ToggleEvent::Flip, enabled: &bool| (!enabled, Effect::none()),
+        |enabled: &bool, _emitter: &Emitter<ToggleEvent>| *enabled,
+    );
+
+    RouterLogic::new(|model: &AppModel| model.route, base)
+        .child(
+            Route::Counter,
+            counter,
+            Lens::new(
+                |model: &AppModel| &model.count,
+                |model, count| model.count = count,
+            ),
+            |event| match event {
+                AppEvent::Counter(event) => Ok(event),
+                event => Err(event),
+            },
+            AppEvent::Counter,
+            |count: i32| format!("count: {count}"),
+        )
+        .child(
+            Route::Toggle,
+            toggle,
+            Lens::new(
+                |model: &AppModel| &model.enabled,
+                |model, enabled| model.enabled = enabled,
+            ),
+            |event| match event {
+                AppEvent::Toggle(event) => Ok(event),
+                event => Err(event),
+            },
+            AppEvent::Toggle,
+            |enabled: bool| format!("enabled: {enabled}"),
+        )
+}
+
+#[test]
+fn given_a_route_should_run_and_render_only_the_active_child() {
+    let renderer = TestRenderer::new();
+    let mut driver = TestMvuRuntime::new(
+        AppModel {
+            route: Route::Counter,
+            count: 0,
+            enabled: false,
+        },
+        router(),
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .run();
+    let emitter = driver.handle().emitter();
+
+    emitter.emit(AppEvent::Counter(CounterEvent::IncrementTwice));
+    emitter.emit(AppEvent::Toggle(ToggleEvent::Flip));
+    driver.process_events();
+
+    emitter.emit(AppEvent::Navigate(Route::Toggle));
+    emitter.emit(AppEvent::Toggle(ToggleEvent::Flip));
+    emitter.emit(AppEvent::Counter(CounterEvent::Increment));
+    driver.process_events();
+
+    emitter.emit(AppEvent::Navigate(Route::About));
+    driver.process_events();
+
+    renderer.with_renders(|renders| {
+        assert_eq!(
+            renders,
+            &vec![
+                "count: 0".to_string(),
+                "count: 1".to_string(),
+                // The toggle event is ignored while the counter is active
+                "count: 1".to_string(),
+                "count: 2".to_string(),
+                "enabled: false".to_string(),
+                "enabled: true".to_string(),
+                "enabled: true".to_string(),
+                "about".to_string(),
+            ]
+        );
+    });
+}
+
+/// Counter that increments once at startup and declares its view key and deps.
+struct StartingCounter;
+
+impl MvuLogic<CounterEvent, i32, i32> for StartingCounter {
+    fn init(&self, count: i32) -> (i32, Effect<CounterEvent>) {
+        (count, Effect::just(CounterEvent::Increment))
+    }
+
+    fn update(&self, _event: CounterEvent, count: &i32) -> (i32, Effect<CounterEvent>) {
+        (count + 1, Effect::none())
+    }
+
+    fn view(&self, count: &i32, _emitter: &Emitter<CounterEvent>) -> i32 {
+        *count
+    }
+
+    fn view_key(&self, count: &i32) -> Option<u64> {
+        Some(*count as u64)
+    }
+
+    fn view_deps(&self, count: &i32) -> Vec<(&'static str, u64)> {
+        vec![("count", *count as u64)]
+    }
+}
+
+fn router_with_starting_counter() -> impl MvuLogic<AppEvent, AppModel, String> {
+    let base = FnLogic::new(
+        |model: AppModel| (model, Effect::none()),
+        |event: AppEvent, model: &AppModel| match event {
+            AppEvent::Navigate(route) => (
+                AppModel {
+                    route,
+                    ..model.clone()
+                },
+                Effect::none(),
+            ),
+            _ => (model.clone(), Effect::none()),
+        },
+        |_model: &AppModel, _emitter: &Emitter<AppEvent>| String::from("about"),
+    );
+
+    RouterLogic::new(|model: &AppModel| model.route, base).child(
+        Route::Counter,
+        StartingCounter,
+        Lens::new(
+            |model: &AppModel| &model.count,
+            |model, count| model.count = count,
+        ),
+        |event| match event {
+            AppEvent::Counter(event) => Ok(event),
+            event => Err(event),
+        },
+        AppEvent::Counter,
+        |count: i32| format!("count: {count}"),
+    )
+}
+
+#[test]
+fn given_an_active_route_at_start_should_run_its_child_init() {
+    let renderer = TestRenderer::new();
+    let mut driver = TestMvuRuntime::new(
+        AppModel {
+            route: Route::Counter,
+            count: 0,
+            enabled: false,
+        },
+        router_with_starting_counter(),
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .run();
+    driver.process_events();
+
+    renderer.with_renders(|renders| {
+        assert_eq!(renders.last(), Some(&"count: 1".to_string()));
+    });
+}
+
+#[test]
+fn given_an_active_child_should_use_its_view_key_and_deps() {
+    let causes = Arc::new(Mutex::new(Vec::new()));
+    let renderer = TestRenderer::new();
+    let mut driver = TestMvuRuntime::new(
+        AppModel {
+            route: Route::About,
+            count: 0,
+            enabled: false,
+        },
+        router_with_starting_counter(),
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_render_cause({
+        let causes = causes.clone();
+        move |changed: &[&'static str]| causes.lock().unwrap().push(changed.to_vec())
+    })
+    .run();
+    let emitter = driver.handle().emitter();
+
+    emitter.emit(AppEvent::Navigate(Route::Counter));
+    driver.process_events();
+    let views = driver.view_count();
+
+    // Same route and count, so the view is skipped
+    emitter.emit(AppEvent::Navigate(Route::Counter));
+    driver.process_events();
+    assert_eq!(driver.view_count(), views);
+
+    emitter.emit(AppEvent::Counter(CounterEvent::Increment));
+    driver.process_events();
+
+    assert_eq!(driver.view_count(), views + 1);
+    assert_eq!(causes.lock().unwrap().last(), Some(&vec!["count"]));
+    renderer.with_renders(|renders| {
+        assert_eq!(renders.last(), Some(&"count: 1".to_string()));
+    });
+}
@@ -0,0 +1,415 @@
+use super::{build_integration_test, TestEvent, TestModel};
+use oxide_mvu::{Bridge, BroadcastEmitter, Effect, ModelAccess, StateDiff};
+
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn given_queued_events_when_taking_pending_should_return_events_without_processing_them() {
+    let mut test = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+
+    test.renders.with_renders(|renders| {
+        (renders[0].on_increment)();
+        (renders[0].on_increment)();
+        (renders[0].on_increment)();
+    });
+
+    let pending = test.driver.handle().take_pending();
+
+    assert_eq!(
+        pending,
+        vec![
+            TestEvent::Increment,
+            TestEvent::Increment,
+            TestEvent::Increment
+        ]
+    );
+    assert!(test.driver.handle().take_pending().is_empty());
+
+    // Taken events are never processed
+    test.driver.process_events();
+    assert_eq!(test.renders.count(), 1);
+}
+
+#[test]
+fn given_two_channels_when_emitting_through_each_should_count_emissions_per_channel() {
+    let mut test = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+
+    let handle = test.driver.handle();
+    let keyboard = handle.channel("keyboard");
+    let network = handle.channel("network");
+
+    keyboard.emit(TestEvent::Increment);
+    keyboard.emit(TestEvent::Increment);
+    network.emit(TestEvent::Increment);
+
+    assert_eq!(
+        handle.channel_counts(),
+        vec![("keyboard", 2), ("network", 1)]
+    );
+
+    // All channels feed the same queue
+    test.driver.process_events();
+    test.renders.with_renders(|renders| {
+        assert_eq!(renders[3].count, 3);
+    });
+}
+
+#[test]
+fn given_an_effect_forwarding_through_a_bridge_should_update_the_target_runtime() {
+    let mut target = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+    let bridge = Bridge::new(&target.driver.handle());
+
+    let mut source = build_integration_test()
+        .given_an_initial_effect(Effect::forward_to(&bridge, TestEvent::Increment))
+        .build();
+
+    source.driver.process_events();
+    target.driver.process_events();
+
+    assert_eq!(source.renders.count(), 1);
+    assert_eq!(target.renders.count(), 2);
+    target.renders.with_renders(|renders| {
+        assert_eq!(renders[1].count, 1);
+    });
+}
+
+#[test]
+fn given_a_model_set_from_the_handle_should_render_the_new_model() {
+    let mut test = build_integration_test().given_no_initial_event().build();
+
+    test.driver.handle().set_model(TestModel { count: 42 });
+    test.driver.process_events();
+
+    assert_eq!(test.renders.count(), 2);
+    test.renders.with_renders(|renders| {
+        assert_eq!(renders[1].count, 42);
+    });
+}
+
+#[test]
+fn given_a_broadcast_emitter_should_deliver_the_event_to_every_live_runtime() {
+    let mut first = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+    let mut second = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+    let shut_down = build_integration_test().given_no_initial_event().build();
+
+    let mut broadcast = BroadcastEmitter::new();
+    broadcast.add(&first.driver.handle());
+    broadcast.add(&shut_down.driver.handle());
+    broadcast.add(&second.driver.handle());
+    drop(shut_down);
+
+    broadcast.emit(TestEvent::Increment);
+    first.driver.process_events();
+    second.driver.process_events();
+
+    first.renders.with_renders(|renders| {
+        assert_eq!(renders[1].count, 1);
+    });
+    second.renders.with_renders(|renders| {
+        assert_eq!(renders[1].count, 1);
+    });
+}
+
+#[test]
+fn given_a_dropped_runtime_with_a_live_handle_should_skip_it_when_emitting() {
+    let shut_down = build_integration_test().given_no_initial_event().build();
+    let handle = shut_down.driver.handle();
+    let bridge = Bridge::new(&handle);
+    let mut broadcast = BroadcastEmitter::new();
+    broadcast.add(&handle);
+    drop(shut_down);
+
+    assert_eq!(
+        bridge.try_emit(TestEvent::Increment),
+        Err(TestEvent::Increment)
+    );
+    broadcast.emit(TestEvent::Increment);
+    assert!(handle.take_pending().is_empty());
+}
+
+struct AddToCount(i32);
+
+impl StateDiff<TestModel> for AddToCount {
+    fn apply(&self, model: &mut TestModel) {
+        model.count += self.0;
+    }
+}
+
+#[test]
+fn given_a_diff_applied_from_the_handle_should_render_the_patched_model() {
+    let mut test = build_integration_test().given_no_initial_event().build();
+
+    test.driver.handle().set_model(TestModel { count: 5 });
+    test.driver.handle().apply_diff(AddToCount(3));
+    test.driver.process_events();
+
+    assert_eq!(test.renders.count(), 3);
+    test.renders.with_renders(|renders| {
+        assert_eq!(renders[2].count, 8);
+    });
+}
+
+#[test]
+fn given_a_history_subscription_should_replay_the_current_model_then_each_commit() {
+    let mut test = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+
+    test.renders
+        .with_renders(|renders| (renders[0].on_increment)());
+    test.renders
+        .with_renders(|renders| (renders[0].on_increment)());
+    test.driver.process_events();
+
+    let observed = seen.clone();
+    test.driver
+        .handle()
+        .subscribe_history(move |model: &TestModel| observed.lock().unwrap().push(model.count));
+    test.driver.process_events();
+
+    assert_eq!(*seen.lock().unwrap(), vec![2]);
+
+    test.renders
+        .with_renders(|renders| (renders[0].on_increment)());
+    test.driver.handle().set_model(TestModel { count: 10 });
+    test.driver.process_events();
+
+    assert_eq!(*seen.lock().unwrap(), vec![2, 10, 11]);
+}
+
+#[test]
+fn given_suspended_rendering_should_keep_updating_and_render_once_on_resume() {
+    let mut test = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+
+    test.driver.handle().suspend_rendering();
+    for _ in 0..5 {
+        test.renders
+            .with_renders(|renders| (renders[0].on_increment)());
+    }
+
+    assert_eq!(test.renders.count(), 1);
+
+    test.driver.handle().resume_rendering();
+    test.driver.process_events();
+
+    assert_eq!(test.renders.count(), 2);
+    test.renders.with_renders(|renders| {
+        assert_eq!(renders[1].count, 5);
+    });
+}
+
+#[test]
+fn given_a_transaction_with_two_model_replacements_should_render_once_with_the_final_model() {
+    let mut test = build_integration_test().given_no_initial_event().build();
+
+    let result = test.driver.handle().transaction(|handle| {
+        handle.set_model(TestModel { count: 3 });
+        handle.set_model(TestModel { count: 7 });
+        "done"
+    });
+    test.driver.process_events();
+
+    assert_eq!(result, "done");
+    assert_eq!(test.renders.count(), 2);
+    test.renders.with_renders(|renders| {
+        assert_eq!(renders[1].count, 7);
+    });
+}
+
+#[test]
+fn given_a_transaction_emitting_several_events_should_render_once_with_the_final_model() {
+    let mut test = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+
+    test.driver.handle().transaction(|handle| {
+        let emitter = handle.emitter();
+        emitter.emit(TestEvent::Increment);
+        emitter.emit(TestEvent::Increment);
+        emitter.emit(TestEvent::Increment);
+    });
+    test.driver.process_events();
+
+    assert_eq!(test.renders.count(), 2);
+    test.renders.with_renders(|renders| {
+        assert_eq!(renders[1].count, 3);
+    });
+}
+
+#[test]
+fn given_a_one_shot_event_hook_when_two_matching_events_are_processed_should_fire_once() {
+    let mut test = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+    let fired = Arc::new(Mutex::new(Vec::new()));
+
+    let seen = fired.clone();
+    test.driver.handle().once_event(
+        |event| *event == TestEvent::Increment,
+        move |event| seen.lock().unwrap().push(event.clone()),
+    );
+    let emitter = test.driver.handle().emitter();
+    emitter.emit(TestEvent::Set(4));
+    emitter.emit(TestEvent::Increment);
+    emitter.emit(TestEvent::Increment);
+    test.driver.process_events();
+
+    assert_eq!(*fired.lock().unwrap(), vec![TestEvent::Increment]);
+}
+
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn given_a_runtime_on_another_thread_when_waiting_idle_should_return_once_events_are_settled() {
+    use super::build_test_logic;
+    use futures::task::noop_waker;
+    use oxide_mvu::{create_test_spawner, MvuRuntime, TestRenderer};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::mpsc;
+    use std::task::Context;
+    use std::time::Duration;
+
+    let (handle_sender, handle_receiver) = mpsc::channel();
+    let stop = Arc::new(AtomicBool::new(false));
+    let runtime_thread = {
+        let stop = stop.clone();
+        std::thread::spawn(move || {
+            let mut runtime = MvuRuntime::new(
+                TestModel { count: 0 },
+                build_test_logic(Effect::none()),
+                TestRenderer::new(),
+                create_test_spawner(),
+            );
+            handle_sender.send(runtime.handle()).unwrap();
+
+            let waker = noop_waker();
+            let mut cx = Context::from_waker(&waker);
+            while !stop.load(Ordering::Acquire) {
+                let _ = runtime.poll_once(&mut cx);
+                std::thread::yield_now();
+            }
+        })
+    };
+    let handle = handle_receiver.recv().unwrap();
+    let model = ModelAccess::new(&handle);
+
+    let events = handle.emitter();
+    events.emit(TestEvent::Set(10));
+    for _ in 0..3 {
+        events.emit(TestEvent::Increment);
+    }
+    let result = handle.wait_idle(Duration::from_secs(5));
+
+    stop.store(true, Ordering::Release);
+    runtime_thread.join().unwrap();
+    assert_eq!(result, Ok(()));
+    assert_eq!(model.read(|model| model.count), Some(13));
+}
+
+#[test]
+fn given_a_weak_model_observer_when_its_strong_ref_is_dropped_should_no_longer_be_called() {
+    use oxide_mvu::Arc as ObserverArc;
+
+    let mut test = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+    let observed = Arc::new(Mutex::new(Vec::new()));
+    let observer: Box<dyn Fn(&TestModel) + Send + Sync> = {
+        let observed = observed.clone();
+        Box::new(move |model: &TestModel| observed.lock().unwrap().push(model.count))
+    };
+    let observer = ObserverArc::from(observer);
+    test.driver
+        .handle()
+        .subscribe_model_weak(ObserverArc::downgrade(&observer));
+
+    test.renders
+        .with_renders(|renders| (renders[0].on_increment)());
+    test.driver.process_events();
+    drop(observer);
+    test.renders
+        .with_renders(|renders| (renders[0].on_increment)());
+    test.driver.process_events();
+
+    assert_eq!(*observed.lock().unwrap(), vec![0, 1]);
+}
+
+#[test]
+fn given_a_model_access_when_every_clone_is_dropped_should_remove_its_observer() {
+    let mut test = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+    let access = ModelAccess::new(&test.driver.handle());
+    let copy = access.clone();
+    test.driver.process_events();
+    assert_eq!(test.driver.model_observer_count(), 1);
+
+    drop(access);
+    test.renders
+        .with_renders(|renders| (renders[0].on_increment)());
+    test.driver.process_events();
+    assert_eq!(test.driver.model_observer_count(), 1);
+    assert_eq!(copy.read(|model| model.count), Some(1));
+
+    drop(copy);
+    test.renders
+        .with_renders(|renders| (renders[0].on_increment)());
+    test.driver.process_events();
+    assert_eq!(test.driver.model_observer_count(), 0);
+}
+
+#[test]
+fn given_a_queued_event_when_setting_the_model_should_apply_the_event_on_top_of_it() {
+    let mut test = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+    let handle = test.driver.handle();
+
+    handle.emitter().emit(TestEvent::Increment);
+    handle.set_model(TestModel { count: 10 });
+    test.driver.process_events();
+
+    test.renders.with_renders(|renders| {
+        let counts: Vec<i32> = renders.iter().map(|props| props.count).collect();
+        assert_eq!(counts, vec![0, 10, 11]);
+    });
+}
+
+#[test]
+fn given_a_queued_event_when_suspending_rendering_should_not_render_the_event() {
+    let mut test = build_integration_test()
+        .given_no_initial_event()
+        .given_a_noop_on_increment_side_effect()
+        .build();
+    let handle = test.driver.handle();
+
+    handle.emitter().emit(TestEvent::Increment);
+    handle.suspend_rendering();
+    test.driver.process_events();
+
+    assert_eq!(test.renders.count(), 1);
+}
@@ -0,0 +1,146 @@
+use super::{build_test_logic, TestModel};
+use oxide_mvu::{
+    create_test_spawner, Effect, Emitter, FnLogic, MvuRuntime, TestMvuRuntime, TestRenderer,
+};
+
+use futures::task::noop_waker;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+#[test]
+fn given_a_manually_polled_runtime_should_process_events_emitted_between_polls() {
+    let renderer = TestRenderer::new();
+    let mut runtime = MvuRuntime::new(
+        TestModel { count: 0 },
+        build_test_logic(Effect::none()),
+        renderer.clone(),
+        create_test_spawner(),
+    );
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    assert_eq!(runtime.poll_once(&mut cx), Poll::Pending);
+    assert_eq!(renderer.count(), 1);
+
+    renderer.with_renders(|renders| (renders[0].on_increment)());
+    assert_eq!(renderer.count(), 1);
+
+    assert_eq!(runtime.poll_once(&mut cx), Poll::Pending);
+    assert_eq!(renderer.count(), 2);
+    renderer.with_renders(|renders| {
+        assert_eq!(renders[1].count, 1);
+    });
+}
+
+#[test]
+fn given_a_threadsafe_runtime_should_render_like_a_regular_one() {
+    let renderer = TestRenderer::new();
+    let mut runtime = MvuRuntime::new_threadsafe(
+        TestModel { count: 0 },
+        build_test_logic(Effect::none()),
+        renderer.clone(),
+        create_test_spawner(),
+    );
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    assert_eq!(runtime.poll_once(&mut cx), Poll::Pending);
+    assert_eq!(renderer.count(), 1);
+}
+
+struct Tick(Duration);
+
+#[test]
+fn given_a_tick_event_when_ticking_should_integrate_the_simulated_time() {
+    let renderer = TestRenderer::new();
+    let logic = FnLogic::new(
+        |elapsed: Duration| (elapsed, Effect::none()),
+        |Tick(dt): Tick, elapsed: &Duration| (*elapsed + dt, Effect::none()),
+        |elapsed: &Duration, _emitter: &Emitter<Tick>| *elapsed,
+    );
+    let mut runtime = MvuRuntime::new(
+        Duration::ZERO,
+        logic,
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_tick_event(Tick);
+
+    for _ in 0..3 {
+        runtime.tick(Duration::from_millis(16));
+    }
+
+    renderer.with_renders(|renders| {
+        assert_eq!(
+            renders,
+            &vec![
+                Duration::ZERO,
+                Duration::from_millis(16),
+                Duration::from_millis(32),
+                Duration::from_millis(48),
+            ]
+        );
+    });
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum FrameEvent {
+    Tick(Duration),
+    Jump,
+    Land,
+}
+
+#[test]
+fn given_queued_events_when_ticking_from_the_handle_should_process_them_with_a_single_render() {
+    let renderer = TestRenderer::new();
+    let logic = FnLogic::new(
+        |frame: Vec<FrameEvent>| (frame, Effect::none()),
+        |event: FrameEvent, frame: &Vec<FrameEvent>| {
+            let effect = match event {
+                FrameEvent::Jump => Effect::just(FrameEvent::Land),
+                _ => Effect::none(),
+            };
+            let mut frame = frame.clone();
+            frame.push(event);
+            (frame, effect)
+        },
+        |frame: &Vec<FrameEvent>, _emitter: &Emitter<FrameEvent>| frame.clone(),
+    );
+    let mut driver =
+        TestMvuRuntime::new(Vec::new(), logic, renderer.clone(), create_test_spawner())
+            .with_tick_event(FrameEvent::Tick)
+            .run();
+    let handle = driver.handle();
+
+    handle.emitter().emit(FrameEvent::Jump);
+    handle.tick(Duration::from_millis(16));
+    driver.process_events();
+
+    assert_eq!(renderer.count(), 2);
+    renderer.with_renders(|renders| {
+        assert_eq!(
+            renders[1],
+            vec![
+                FrameEvent::Jump,
+                FrameEvent::Tick(Duration::from_millis(16)),
+                FrameEvent::Land,
+            ]
+        );
+    });
+}
+
+fn assert_send<T: Send>(_: &T) {}
+
+#[test]
+fn given_send_parts_should_build_a_runtime_whose_run_future_can_be_spawned() {
+    let logic = FnLogic::new(
+        |count: i32| (count, Effect::none()),
+        |_: (), count: &i32| (*count + 1, Effect::none()),
+        |count: &i32, _emitter: &Emitter<()>| *count,
+    );
+    let mut runtime = MvuRuntime::new(0, logic, TestRenderer::new(), create_test_spawner());
+    runtime.add_view(|count: &i32, _| *count, Box::new(TestRenderer::new()));
+
+    assert_send(&runtime);
+    assert_send(&runtime.run());
+}
@@ -3,6 +3,7 @@ use oxide_mvu::{Effect, Emitter, MvuLogic};
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) enum TestEvent {
     Increment,
+    Set(i32),
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -44,6 +45,7 @@ impl MvuLogic<TestEvent, TestModel, TestProps> for TestLogic {
                 };
                 (new_model, self.effects.on_increment_side_effect())
             }
+            TestEvent::Set(count) => (TestModel { count }, Effect::none()),
         }
     }
 
@@ -0,0 +1,181 @@
+use super::{TestEvent, TestModel, TestProps};
+use oxide_mvu::{
+    create_test_spawner, Effect, Emitter, FnLogic, MvuLogic, TestMvuRuntime, TestRenderer,
+};
+
+fn logic_emitting_during_view() -> impl MvuLogic<TestEvent, TestModel, TestProps> {
+    FnLogic::new(
+        |model: TestModel| (model, Effect::none()),
+        |event: TestEvent, model: &TestModel| match event {
+            TestEvent::Increment => (
+                TestModel {
+                    count: model.count + 1,
+                },
+                Effect::none(),
+            ),
+            TestEvent::Set(count) => (TestModel { count }, Effect::none()),
+        },
+        |model: &TestModel, emitter: &Emitter<TestEvent>| {
+            if model.count == 0 {
+                emitter.emit(TestEvent::Set(5));
+            }
+            let emitter = emitter.clone();
+            TestProps {
+                count: model.count,
+                on_increment: Box::new(move || emitter.emit(TestEvent::Increment)),
+            }
+        },
+    )
+}
+
+#[test]
+fn given_normal_mode_when_view_emits_should_process_the_event() {
+    let renderer = TestRenderer::new();
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        logic_emitting_during_view(),
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .run();
+    driver.process_events();
+
+    renderer.with_renders(|renders| {
+        let counts: Vec<i32> = renders.iter().map(|props| props.count).collect();
+        assert_eq!(counts, vec![0, 5]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "event emitted during view")]
+fn given_strict_mode_when_view_emits_should_panic() {
+    TestMvuRuntime::new(
+        TestModel { count: 0 },
+        logic_emitting_during_view(),
+        TestRenderer::new(),
+        create_test_spawner(),
+    )
+    .with_strict_view()
+    .run();
+}
+
+#[test]
+fn given_strict_mode_when_a_props_callback_emits_should_process_the_event() {
+    let renderer = TestRenderer::new();
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 1 },
+        logic_emitting_during_view(),
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_strict_view()
+    .run();
+
+    renderer.with_renders(|renders| (renders[0].on_increment)());
+    driver.process_events();
+
+    renderer.with_renders(|renders| assert_eq!(renders[1].count, 2));
+}
+
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn given_strict_mode_when_a_view_panics_should_keep_accepting_props_callback_emits() {
+    use oxide_mvu::PanicPolicy;
+
+    let renderer = TestRenderer::new();
+    let logic = FnLogic::new(
+        |model: TestModel| (model, Effect::none()),
+        |event: TestEvent, model: &TestModel| match event {
+            TestEvent::Increment => (
+                TestModel {
+                    count: model.count + 1,
+                },
+                Effect::none(),
+            ),
+            TestEvent::Set(count) => (TestModel { count }, Effect::none()),
+        },
+        |model: &TestModel, emitter: &Emitter<TestEvent>| {
+            if model.count < 0 {
+                panic!("negative count");
+            }
+            let emitter = emitter.clone();
+            TestProps {
+                count: model.count,
+                on_increment: Box::new(move || emitter.emit(TestEvent::Increment)),
+            }
+        },
+    );
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        logic,
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_strict_view()
+    .with_panic_policy(PanicPolicy::SkipEvent)
+    .run();
+
+    driver.handle().emitter().emit(TestEvent::Set(-1));
+    driver.process_events();
+    renderer.with_renders(|renders| (renders[0].on_increment)());
+    driver.process_events();
+
+    renderer.with_renders(|renders| {
+        let counts: Vec<i32> = renders.iter().map(|props| props.count).collect();
+        assert_eq!(counts, vec![0, 1]);
+    });
+}
+
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn given_strict_mode_when_another_thread_emits_during_a_view_should_process_the_event() {
+    use std::sync::{Arc, Mutex};
+
+    let captured: Arc<Mutex<Option<Emitter<TestEvent>>>> = Arc::default();
+    let renderer = TestRenderer::new();
+    let logic = FnLogic::new(
+        |model: TestModel| (model, Effect::none()),
+        |event: TestEvent, model: &TestModel| match event {
+            TestEvent::Increment => (
+                TestModel {
+                    count: model.count + 1,
+                },
+                Effect::none(),
+            ),
+            TestEvent::Set(count) => (TestModel { count }, Effect::none()),
+        },
+        {
+            let captured = captured.clone();
+            move |model: &TestModel, emitter: &Emitter<TestEvent>| {
+                let previous = captured.lock().unwrap().replace(emitter.clone());
+                if let (1, Some(previous)) = (model.count, previous) {
+                    // A UI thread firing a Props callback while this view runs
+                    std::thread::scope(|scope| {
+                        scope.spawn(|| previous.emit(TestEvent::Set(10)));
+                    });
+                }
+                let emitter = emitter.clone();
+                TestProps {
+                    count: model.count,
+                    on_increment: Box::new(move || emitter.emit(TestEvent::Increment)),
+                }
+            }
+        },
+    );
+    let mut driver = TestMvuRuntime::new(
+        TestModel { count: 0 },
+        logic,
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .with_strict_view()
+    .run();
+
+    renderer.with_renders(|renders| (renders[0].on_increment)());
+    driver.process_events();
+
+    renderer.with_renders(|renders| {
+        let counts: Vec<i32> = renders.iter().map(|props| props.count).collect();
+        assert_eq!(counts, vec![0, 1, 10]);
+    });
+}
@@ -0,0 +1,28 @@
+use oxide_mvu::{Renderer, TestClock, TestRenderer, ThrottledRenderer};
+
+use std::time::Duration;
+
+#[test]
+fn given_a_burst_of_renders_should_forward_a_throttled_subset_and_the_final_props() {
+    let clock = TestClock::new();
+    let inner = TestRenderer::new();
+    let mut renderer =
+        ThrottledRenderer::new(inner.clone(), clock.clone(), Duration::from_millis(10));
+    let trailing = renderer.clone();
+
+    renderer.render(0);
+    clock.advance(Duration::from_millis(4));
+    renderer.render(1);
+    renderer.render(2);
+    assert!(!trailing.flush_trailing());
+
+    clock.advance(Duration::from_millis(8));
+    renderer.render(3);
+    clock.advance(Duration::from_millis(3));
+    renderer.render(4);
+    clock.advance(Duration::from_millis(7));
+    assert!(trailing.flush_trailing());
+    assert!(!trailing.flush_trailing());
+
+    inner.with_renders(|renders| assert_eq!(*renders, vec![0, 3, 4]));
+}
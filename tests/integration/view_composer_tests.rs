@@ -0,0 +1,95 @@
+use super::{TestEvent, TestModel, TestProps};
+use oxide_mvu::{
+    create_test_spawner, Effect, Emitter, FnLogic, MvuLogic, TestMvuRuntime, TestRenderer,
+    ViewComposer,
+};
+
+#[derive(Clone)]
+struct DashboardModel {
+    left: TestModel,
+    right: TestModel,
+}
+
+enum DashboardEvent {
+    Left(TestEvent),
+    Right(TestEvent),
+}
+
+struct DashboardProps {
+    left: TestProps,
+    right: TestProps,
+}
+
+fn counter() -> impl MvuLogic<TestEvent, TestModel, TestProps> {
+    FnLogic::new(
+        |model: TestModel| (model, Effect::none()),
+        |event: TestEvent, model: &TestModel| match event {
+            TestEvent::Increment => (
+                TestModel {
+                    count: model.count + 1,
+                },
+                Effect::none(),
+            ),
+            TestEvent::Set(count) => (TestModel { count }, Effect::none()),
+        },
+        |model: &TestModel, emitter: &Emitter<TestEvent>| {
+            let emitter = emitter.clone();
+            TestProps {
+                count: model.count,
+                on_increment: Box::new(move || emitter.emit(TestEvent::Increment)),
+            }
+        },
+    )
+}
+
+#[test]
+fn given_two_composed_counters_should_route_each_childs_events_to_its_own_model() {
+    let renderer = TestRenderer::new();
+    let logic = FnLogic::new(
+        |model: DashboardModel| (model, Effect::none()),
+        |event: DashboardEvent, model: &DashboardModel| {
+            let counter = counter();
+            let mut next = model.clone();
+            match event {
+                DashboardEvent::Left(event) => next.left = counter.update(event, &model.left).0,
+                DashboardEvent::Right(event) => next.right = counter.update(event, &model.right).0,
+            }
+            (next, Effect::none())
+        },
+        |model: &DashboardModel, emitter: &Emitter<DashboardEvent>| {
+            let counter = counter();
+            let composer = ViewComposer::new(model, emitter);
+            DashboardProps {
+                left: composer.child(|model| &model.left, &counter, DashboardEvent::Left),
+                right: composer.child(|model| &model.right, &counter, DashboardEvent::Right),
+            }
+        },
+    );
+    let mut driver = TestMvuRuntime::new(
+        DashboardModel {
+            left: TestModel { count: 2 },
+            right: TestModel { count: 5 },
+        },
+        logic,
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .run();
+
+    renderer.with_renders(|renders| {
+        assert_eq!(renders[0].left.count, 2);
+        assert_eq!(renders[0].right.count, 5);
+        (renders[0].right.on_increment)();
+    });
+    driver.process_events();
+    renderer.with_renders(|renders| (renders[1].left.on_increment)());
+    driver.process_events();
+
+    let counts = renderer.with_renders(|renders| {
+        renders
+            .iter()
+            .map(|props| (props.left.count, props.right.count))
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(counts, vec![(2, 5), (2, 6), (3, 6)]);
+}
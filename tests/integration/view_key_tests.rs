@@ -0,0 +1,129 @@
+use oxide_mvu::{create_test_spawner, Effect, Emitter, MvuLogic, TestMvuRuntime, TestRenderer};
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[derive(Clone)]
+enum Event {
+    Increment,
+    Touch,
+}
+
+#[derive(Clone)]
+struct Model {
+    count: i32,
+    touches: u32,
+}
+
+struct Props {
+    count: i32,
+    on_increment: Box<dyn Fn() + Send>,
+    on_touch: Box<dyn Fn() + Send>,
+}
+
+struct KeyedLogic {
+    view_calls: Arc<AtomicUsize>,
+}
+
+impl MvuLogic<Event, Model, Props> for KeyedLogic {
+    fn init(&self, model: Model) -> (Model, Effect<Event>) {
+        (model, Effect::none())
+    }
+
+    fn update(&self, event: Event, model: &Model) -> (Model, Effect<Event>) {
+        let new_model = match event {
+            Event::Increment => Model {
+                count: model.count + 1,
+                ..model.clone()
+            },
+            Event::Touch => Model {
+                touches: model.touches + 1,
+                ..model.clone()
+            },
+        };
+        (new_model, Effect::none())
+    }
+
+    fn view(&self, model: &Model, emitter: &Emitter<Event>) -> Props {
+        self.view_calls.fetch_add(1, Ordering::SeqCst);
+
+        let increment_emitter = emitter.clone();
+        let touch_emitter = emitter.clone();
+        Props {
+            count: model.count,
+            on_increment: Box::new(move || increment_emitter.emit(Event::Increment)),
+            on_touch: Box::new(move || touch_emitter.emit(Event::Touch)),
+        }
+    }
+
+    fn view_key(&self, model: &Model) -> Option<u64> {
+        // Props only depend on the count
+        Some(model.count as u64)
+    }
+}
+
+#[test]
+fn given_a_view_key_when_only_an_irrelevant_field_changes_should_not_rerun_view() {
+    let view_calls = Arc::new(AtomicUsize::new(0));
+    let renderer = TestRenderer::new();
+    let logic = KeyedLogic {
+        view_calls: view_calls.clone(),
+    };
+    let mut driver = TestMvuRuntime::new(
+        Model {
+            count: 0,
+            touches: 0,
+        },
+        logic,
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .run();
+
+    renderer.with_renders(|renders| (renders[0].on_touch)());
+    driver.process_events();
+
+    assert_eq!(view_calls.load(Ordering::SeqCst), 1);
+    assert_eq!(renderer.count(), 1);
+
+    renderer.with_renders(|renders| (renders[0].on_increment)());
+    driver.process_events();
+
+    assert_eq!(view_calls.load(Ordering::SeqCst), 2);
+    assert_eq!(renderer.count(), 2);
+    renderer.with_renders(|renders| {
+        assert_eq!(renders[1].count, 1);
+    });
+}
+
+#[test]
+fn given_a_view_key_when_the_key_is_unchanged_should_not_increase_the_view_count() {
+    let renderer = TestRenderer::new();
+    let logic = KeyedLogic {
+        view_calls: Arc::new(AtomicUsize::new(0)),
+    };
+    let mut driver = TestMvuRuntime::new(
+        Model {
+            count: 0,
+            touches: 0,
+        },
+        logic,
+        renderer.clone(),
+        create_test_spawner(),
+    )
+    .run();
+
+    assert_eq!(driver.view_count(), 1);
+
+    renderer.with_renders(|renders| (renders[0].on_touch)());
+    renderer.with_renders(|renders| (renders[0].on_touch)());
+    driver.process_events();
+
+    assert_eq!(driver.view_count(), 1);
+
+    renderer.with_renders(|renders| (renders[0].on_increment)());
+    driver.process_events();
+
+    assert_eq!(driver.view_count(), 2);
+    assert_eq!(renderer.count(), 2);
+}